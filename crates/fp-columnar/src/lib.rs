@@ -54,14 +54,17 @@
 //! - **fp-index** uses [`Column`] internally for some MultiIndex
 //!   level storage.
 
+use std::borrow::Cow;
 use std::sync::{Arc, OnceLock};
 
 use fp_types::{
-    DType, Interval, IntervalClosed, NullKind, Period, PeriodFreq, Scalar, SparseDType, Timedelta,
-    Timestamp, TypeError, cast_scalar, cast_scalar_owned, common_dtype, infer_dtype, nanall,
-    nanany, nanargmax, nanargmin, nancummax, nancummin, nancumprod, nancumsum, nankurt, nanmax,
-    nanmean, nanmedian, nanmin, nannunique, nanprod, nanptp, nanquantile, nansem, nanskew, nanstd,
-    nansum, nanvar,
+    DType, Decimal, DecimalRounding, Interval, IntervalClosed, NullKind, NumericDowncast, Period,
+    PeriodFreq, Scalar, SparseDType, StructField, StructType, TimeZone, Timedelta, Timestamp,
+    TypeError, TzAmbiguousPolicy, TzNonexistentPolicy, cast_scalar, cast_scalar_owned,
+    common_dtype, common_dtype_with_scalar, downcast_numeric_dtype, infer_dtype, nanall, nanany,
+    nanargmax, nanargmin, nancummax, nancummin, nancumprod, nancumsum, nankurt, nanmax, nanmean,
+    nanmedian, nanmin, nannunique, nanprod, nanptp, nanquantile, nansem, nanskew, nanstd, nansum,
+    nanvar,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
@@ -837,6 +840,29 @@ pub enum ColumnData {
     /// `i64::MIN` ordinals are NaT.
     Period(Vec<i64>, PeriodFreq),
     Interval(Vec<Interval>),
+    /// Decimal column: unscaled values at the column-uniform scale.
+    Decimal128(Vec<i128>, u8),
+    /// Sized numeric columns keep numpy's width: one byte per `int8` row,
+    /// and `uint64` values above `i64::MAX` stay exact.
+    Int8(Arc<[i8]>),
+    Int16(Arc<[i16]>),
+    Int32(Arc<[i32]>),
+    UInt8(Arc<[u8]>),
+    UInt16(Arc<[u16]>),
+    UInt32(Arc<[u32]>),
+    UInt64(Arc<[u64]>),
+    Float32(Arc<[f32]>),
+    /// List column in Arrow's offset-buffer layout: row `i` is
+    /// `values[offsets[i]..offsets[i + 1]]`. Missing rows span nothing.
+    List {
+        offsets: Vec<usize>,
+        values: Box<Column>,
+    },
+    /// Struct column: one child column per field, each `len` rows long.
+    Struct {
+        len: usize,
+        fields: Vec<(String, Column)>,
+    },
 }
 
 /// Exact witness for all-valid Int64 columns whose key at row `i` is
@@ -1429,12 +1455,33 @@ impl ColumnData {
     #[must_use]
     pub fn from_scalars(values: &[Scalar], dtype: DType) -> Self {
         match dtype {
+            DType::Int8 => Self::Int8(sized_int_buffer(values)),
+            DType::Int16 => Self::Int16(sized_int_buffer(values)),
+            DType::Int32 => Self::Int32(sized_int_buffer(values)),
+            DType::UInt8 => Self::UInt8(sized_int_buffer(values)),
+            DType::UInt16 => Self::UInt16(sized_int_buffer(values)),
+            DType::UInt32 => Self::UInt32(sized_int_buffer(values)),
+            DType::UInt64 => Self::UInt64(sized_int_buffer(values)),
+            DType::Float32 => Self::Float32(
+                values
+                    .iter()
+                    .map(|v| match v {
+                        Scalar::Float64(f) => *f as f32,
+                        Scalar::Int64(i) => *i as f32,
+                        Scalar::UInt64(u) => *u as f32,
+                        Scalar::Bool(b) => f32::from(u8::from(*b)),
+                        _ => 0.0,
+                    })
+                    .collect(),
+            ),
             DType::Float64 => {
                 let data: Vec<f64> = values
                     .iter()
                     .map(|v| match v {
                         Scalar::Float64(f) => *f,
                         Scalar::Int64(i) => *i as f64,
+                        Scalar::UInt64(u) => *u as f64,
+                        Scalar::Decimal(d) => d.to_f64(),
                         Scalar::Bool(true) => 1.0,
                         Scalar::Bool(false) => 0.0,
                         _ => 0.0, // sentinel for invalid positions
@@ -1496,7 +1543,7 @@ impl ColumnData {
                     .collect();
                 Self::Timedelta64(data)
             }
-            DType::Datetime64 => {
+            DType::Datetime64 | DType::DatetimeTz(_) => {
                 let data: Vec<i64> = values
                     .iter()
                     .map(|v| match v {
@@ -1537,6 +1584,75 @@ impl ColumnData {
                     .collect();
                 Self::Interval(data)
             }
+            DType::Decimal { scale, .. } => {
+                // Column::new already cast every value to the dtype's scale.
+                let data: Vec<i128> = values
+                    .iter()
+                    .map(|v| match v {
+                        Scalar::Decimal(d) => d
+                            .rescale(scale, DecimalRounding::HalfEven)
+                            .map_or(0, |d| d.value),
+                        _ => 0,
+                    })
+                    .collect();
+                Self::Decimal128(data, scale)
+            }
+            DType::List(list) => {
+                let mut offsets = Vec::with_capacity(values.len() + 1);
+                offsets.push(0);
+                let mut total = 0;
+                for value in values {
+                    if let Scalar::List(items) = value {
+                        total += items.len();
+                    }
+                    offsets.push(total);
+                }
+                let flatten = || {
+                    values
+                        .iter()
+                        .filter_map(|value| match value {
+                            Scalar::List(items) => Some(items.iter().cloned()),
+                            _ => None,
+                        })
+                        .flatten()
+                        .collect::<Vec<_>>()
+                };
+                Self::List {
+                    offsets,
+                    values: Box::new(Column::nested_child(list.inner(), flatten)),
+                }
+            }
+            DType::Struct(struct_type) => {
+                let fields = struct_type
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        let project = || {
+                            values
+                                .iter()
+                                .map(|value| match value {
+                                    Scalar::Struct(pairs) => pairs
+                                        .iter()
+                                        .find(|(name, _)| *name == field.name)
+                                        .map(|(_, v)| v.clone()),
+                                    _ => None,
+                                })
+                                .map(|v| {
+                                    v.unwrap_or_else(|| Scalar::missing_for_dtype(field.dtype))
+                                })
+                                .collect::<Vec<_>>()
+                        };
+                        (
+                            field.name.clone(),
+                            Column::nested_child(field.dtype, project),
+                        )
+                    })
+                    .collect();
+                Self::Struct {
+                    len: values.len(),
+                    fields,
+                }
+            }
         }
     }
 
@@ -1632,6 +1748,64 @@ impl ColumnData {
                     }
                 })
                 .collect(),
+            Self::Decimal128(data, scale) => data
+                .iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    if !validity.get(i) {
+                        Scalar::missing_for_dtype(dtype)
+                    } else {
+                        Scalar::from(Decimal::new(*v, *scale))
+                    }
+                })
+                .collect(),
+            Self::Int8(data) => {
+                sized_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::Int16(data) => {
+                sized_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::Int32(data) => {
+                sized_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::UInt8(data) => {
+                sized_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::UInt16(data) => {
+                sized_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::UInt32(data) => {
+                sized_to_scalars(data, dtype, validity, |v| Scalar::Int64(v.into()))
+            }
+            Self::UInt64(data) => sized_to_scalars(data, dtype, validity, Scalar::UInt64),
+            Self::Float32(data) => {
+                sized_to_scalars(data, dtype, validity, |v| Scalar::Float64(v.into()))
+            }
+            Self::List { offsets, values } => offsets
+                .windows(2)
+                .enumerate()
+                .map(|(i, span)| {
+                    if !validity.get(i) {
+                        Scalar::missing_for_dtype(dtype)
+                    } else {
+                        Scalar::List(values.values()[span[0]..span[1]].to_vec())
+                    }
+                })
+                .collect(),
+            Self::Struct { len, fields } => (0..*len)
+                .map(|i| {
+                    if !validity.get(i) {
+                        Scalar::missing_for_dtype(dtype)
+                    } else {
+                        Scalar::Struct(
+                            fields
+                                .iter()
+                                .map(|(name, column)| (name.clone(), column.values()[i].clone()))
+                                .collect(),
+                        )
+                    }
+                })
+                .collect(),
         }
     }
 
@@ -1646,6 +1820,17 @@ impl ColumnData {
             Self::Datetime64(d) => d.len(),
             Self::Period(d, _) => d.len(),
             Self::Interval(d) => d.len(),
+            Self::Decimal128(d, _) => d.len(),
+            Self::Int8(d) => d.len(),
+            Self::Int16(d) => d.len(),
+            Self::Int32(d) => d.len(),
+            Self::UInt8(d) => d.len(),
+            Self::UInt16(d) => d.len(),
+            Self::UInt32(d) => d.len(),
+            Self::UInt64(d) => d.len(),
+            Self::Float32(d) => d.len(),
+            Self::List { offsets, .. } => offsets.len().saturating_sub(1),
+            Self::Struct { len, .. } => *len,
         }
     }
 
@@ -1653,6 +1838,96 @@ impl ColumnData {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// The sized dtype this buffer stores, or `None` for the 64-bit and
+    /// non-numeric variants.
+    #[must_use]
+    pub fn sized_dtype(&self) -> Option<DType> {
+        Some(match self {
+            Self::Int8(_) => DType::Int8,
+            Self::Int16(_) => DType::Int16,
+            Self::Int32(_) => DType::Int32,
+            Self::UInt8(_) => DType::UInt8,
+            Self::UInt16(_) => DType::UInt16,
+            Self::UInt32(_) => DType::UInt32,
+            Self::UInt64(_) => DType::UInt64,
+            Self::Float32(_) => DType::Float32,
+            _ => return None,
+        })
+    }
+
+    /// Exact integer view of an integer or bool buffer, widened to `i128` so
+    /// mixed signed/unsigned operands never round through `f64`.
+    fn widen_to_i128(&self) -> Option<Vec<i128>> {
+        fn widen<T: Copy + Into<i128>>(data: &[T]) -> Option<Vec<i128>> {
+            Some(data.iter().map(|&v| v.into()).collect())
+        }
+        match self {
+            Self::Int8(d) => widen(d),
+            Self::Int16(d) => widen(d),
+            Self::Int32(d) => widen(d),
+            Self::Int64(d) => widen(d),
+            Self::UInt8(d) => widen(d),
+            Self::UInt16(d) => widen(d),
+            Self::UInt32(d) => widen(d),
+            Self::UInt64(d) => widen(d),
+            Self::Bool(d) => Some(d.iter().map(|&b| i128::from(b)).collect()),
+            _ => None,
+        }
+    }
+
+    /// Narrow `i128` results into `dtype`'s buffer with numpy's two's
+    /// complement wrap-around (`int8(100) + int8(100) == -56`).
+    fn wrap_from_i128(dtype: DType, values: &[i128]) -> Option<Self> {
+        fn wrap<T>(values: &[i128], narrow: fn(i128) -> T) -> Arc<[T]> {
+            values.iter().map(|&v| narrow(v)).collect()
+        }
+        Some(match dtype {
+            DType::Int8 => Self::Int8(wrap(values, |v| v as i8)),
+            DType::Int16 => Self::Int16(wrap(values, |v| v as i16)),
+            DType::Int32 => Self::Int32(wrap(values, |v| v as i32)),
+            DType::UInt8 => Self::UInt8(wrap(values, |v| v as u8)),
+            DType::UInt16 => Self::UInt16(wrap(values, |v| v as u16)),
+            DType::UInt32 => Self::UInt32(wrap(values, |v| v as u32)),
+            DType::UInt64 => Self::UInt64(wrap(values, |v| v as u64)),
+            _ => return None,
+        })
+    }
+}
+
+/// Narrow integer buffer for a sized column. Construction range-checked
+/// every value, so valid slots always fit; invalid slots hold 0.
+fn sized_int_buffer<T>(values: &[Scalar]) -> Arc<[T]>
+where
+    T: TryFrom<i64> + TryFrom<u64> + Default,
+{
+    values
+        .iter()
+        .map(|v| match v {
+            Scalar::Int64(i) => T::try_from(*i).unwrap_or_default(),
+            Scalar::UInt64(u) => T::try_from(*u).unwrap_or_default(),
+            Scalar::Bool(b) => T::try_from(i64::from(*b)).unwrap_or_default(),
+            _ => T::default(),
+        })
+        .collect()
+}
+
+fn sized_to_scalars<T: Copy>(
+    data: &[T],
+    dtype: DType,
+    validity: &ValidityMask,
+    to_scalar: impl Fn(T) -> Scalar,
+) -> Vec<Scalar> {
+    data.iter()
+        .enumerate()
+        .map(|(i, &v)| {
+            if validity.get(i) {
+                to_scalar(v)
+            } else {
+                Scalar::missing_for_dtype(dtype)
+            }
+        })
+        .collect()
 }
 
 /// Compare two non-missing scalars using the given comparison operator.
@@ -1660,6 +1935,20 @@ impl ColumnData {
 /// Both scalars are converted to `f64` for comparison. For `Utf8` values,
 /// lexicographic ordering is used. Returns `Err` for incompatible types.
 fn scalar_compare(left: &Scalar, right: &Scalar, op: ComparisonOp) -> Result<bool, ColumnError> {
+    // Integer pairs compare exactly, `uint64` included (f64 merges values
+    // above 2^53).
+    if left.is_integer() && right.is_integer() {
+        let ordering = left.semantic_cmp(right);
+        return Ok(match op {
+            ComparisonOp::Gt => ordering.is_gt(),
+            ComparisonOp::Lt => ordering.is_lt(),
+            ComparisonOp::Eq => ordering.is_eq(),
+            ComparisonOp::Ne => ordering.is_ne(),
+            ComparisonOp::Ge => ordering.is_ge(),
+            ComparisonOp::Le => ordering.is_le(),
+        });
+    }
+
     // Coerce differing numeric types to avoid precision loss (e.g. Bool vs Int64).
     let left_dtype = left.dtype();
     let right_dtype = right.dtype();
@@ -1682,6 +1971,24 @@ fn scalar_compare(left: &Scalar, right: &Scalar, op: ComparisonOp) -> Result<boo
         }
     }
 
+    // Decimals compare exactly against each other and against integers.
+    if (left.is_decimal() || right.is_decimal())
+        && left.is_numeric()
+        && right.is_numeric()
+        && !left.is_float()
+        && !right.is_float()
+    {
+        let ordering = left.semantic_cmp(right);
+        return Ok(match op {
+            ComparisonOp::Gt => ordering.is_gt(),
+            ComparisonOp::Lt => ordering.is_lt(),
+            ComparisonOp::Eq => ordering.is_eq(),
+            ComparisonOp::Ne => ordering.is_ne(),
+            ComparisonOp::Ge => ordering.is_ge(),
+            ComparisonOp::Le => ordering.is_le(),
+        });
+    }
+
     // Handle Utf8 comparisons separately (lexicographic).
     if let (Scalar::Utf8(a), Scalar::Utf8(b)) = (left, right) {
         return Ok(match op {
@@ -1694,6 +2001,20 @@ fn scalar_compare(left: &Scalar, right: &Scalar, op: ComparisonOp) -> Result<boo
         });
     }
 
+    // Temporal values compare as i64 nanoseconds (exact, unlike to_f64).
+    if let (Scalar::Datetime64(a), Scalar::Datetime64(b))
+    | (Scalar::Timedelta64(a), Scalar::Timedelta64(b)) = (left, right)
+    {
+        return Ok(match op {
+            ComparisonOp::Gt => a > b,
+            ComparisonOp::Lt => a < b,
+            ComparisonOp::Eq => a == b,
+            ComparisonOp::Ne => a != b,
+            ComparisonOp::Ge => a >= b,
+            ComparisonOp::Le => a <= b,
+        });
+    }
+
     // Handle Bool comparisons (false < true).
     if let (Scalar::Bool(a), Scalar::Bool(b)) = (left, right) {
         return Ok(match op {
@@ -1960,6 +2281,41 @@ fn python_mod_i64(lhs: i64, rhs: i64) -> i64 {
     value
 }
 
+/// Python floor division / modulo / power over `i128`, the exact working
+/// width for sized integer kernels (operands are at most 64 bits, so only
+/// `pow` can overflow, and it wraps like the narrower result will).
+fn python_floor_div_i128(lhs: i128, rhs: i128) -> i128 {
+    debug_assert_ne!(rhs, 0);
+    let quotient = lhs / rhs;
+    if lhs % rhs != 0 && ((lhs < 0) != (rhs < 0)) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+fn python_mod_i128(lhs: i128, rhs: i128) -> i128 {
+    debug_assert_ne!(rhs, 0);
+    let remainder = lhs % rhs;
+    if remainder != 0 && ((remainder < 0) != (rhs < 0)) {
+        remainder + rhs
+    } else {
+        remainder
+    }
+}
+
+fn wrapping_pow_i128(mut base: i128, mut exponent: u128) -> i128 {
+    let mut acc: i128 = 1;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            acc = acc.wrapping_mul(base);
+        }
+        base = base.wrapping_mul(base);
+        exponent >>= 1;
+    }
+    acc
+}
+
 /// AG-10: Vectorized binary arithmetic on `&[i64]` slices.
 ///
 /// Produces `i64` results for Add/Sub/Mul. For Div, returns `None`
@@ -2276,6 +2632,16 @@ enum ScalarValues {
         validity: ValidityMask,
         values: OnceLock<Vec<Scalar>>,
     },
+    /// Sized numeric backing (`Int8`…`UInt64`, `Float32`): the native-width
+    /// buffer, shared with the column's `ColumnData` cache, plus validity.
+    /// Narrow ints materialize as `Int64`, `UInt64` as `UInt64` and `Float32`
+    /// widened to `Float64`; invalid slots are `missing_for_dtype(dtype)`.
+    LazySizedNumeric {
+        dtype: DType,
+        data: ColumnData,
+        validity: ValidityMask,
+        values: OnceLock<Vec<Scalar>>,
+    },
     LazyAllValidBool {
         data: Arc<[bool]>,
         affine_selection: OnceLock<Option<BoolAffineSelectionWitness>>,
@@ -2592,6 +2958,10 @@ enum ScalarValues {
 }
 
 type Utf8ArcViewSource = (Arc<[u8]>, Arc<[usize]>, usize);
+
+/// Tz-aware operands re-read as naive UTC `datetime64[ns]` columns.
+type NaiveOperands<'a> = (Cow<'a, Column>, Cow<'a, Column>);
+
 pub type Utf8ArcBuffers = (Arc<[u8]>, Arc<[usize]>);
 
 impl ScalarValues {
@@ -2924,6 +3294,15 @@ impl ScalarValues {
         }
     }
 
+    fn lazy_sized_numeric(dtype: DType, data: ColumnData, validity: ValidityMask) -> Self {
+        Self::LazySizedNumeric {
+            dtype,
+            data,
+            validity,
+            values: OnceLock::new(),
+        }
+    }
+
     fn lazy_all_valid_bool(data: Vec<bool>) -> Self {
         Self::lazy_all_valid_bool_arc(Arc::from(data))
     }
@@ -4637,6 +5016,14 @@ impl ScalarValues {
                         .collect()
                 })
                 .as_slice(),
+            Self::LazySizedNumeric {
+                dtype,
+                data,
+                validity,
+                values,
+            } => values
+                .get_or_init(|| data.to_scalars(*dtype, validity))
+                .as_slice(),
             Self::LazyRepeatRunsInt64 {
                 runs,
                 total_len,
@@ -5061,6 +5448,7 @@ impl ScalarValues {
             } => null_prefix + source_len + null_suffix,
             Self::LazyNullableInt64 { data, .. } => data.len(),
             Self::LazyNullableBool { data, .. } => data.len(),
+            Self::LazySizedNumeric { data, .. } => data.len(),
             Self::LazyRepeatRunsInt64 { total_len, .. } => *total_len,
             Self::LazyRepeatValuesInt64 { total_len, .. } => *total_len,
             Self::LazyRepeatedSlicesInt64 { total_len, .. } => *total_len,
@@ -5461,6 +5849,12 @@ impl Clone for ScalarValues {
             Self::LazyNullableBool { data, validity, .. } => {
                 Self::lazy_nullable_bool(data.clone(), validity.clone())
             }
+            Self::LazySizedNumeric {
+                dtype,
+                data,
+                validity,
+                ..
+            } => Self::lazy_sized_numeric(*dtype, data.clone(), validity.clone()),
             Self::LazyRepeatRunsInt64 {
                 runs, total_len, ..
             } => Self::lazy_repeat_runs_int64(runs.clone(), *total_len),
@@ -5728,6 +6122,8 @@ impl Clone for Column {
             {
                 Some(d.clone())
             }
+            // Sized buffers are Arc-shared with the values backing.
+            Some(d) if self.dtype.is_sized_numeric() => Some(d.clone()),
             _ => None,
         };
         Self {
@@ -6500,6 +6896,7 @@ fn compare_scalars_na_last(left: &Scalar, right: &Scalar, ascending: bool) -> st
         (false, false) => {
             let ord = match (left, right) {
                 (Scalar::Int64(a), Scalar::Int64(b)) => a.cmp(b),
+                (a, b) if a.is_integer() && b.is_integer() => a.semantic_cmp(b),
                 (Scalar::Float64(a), Scalar::Float64(b)) => {
                     a.partial_cmp(b).unwrap_or(Ordering::Equal)
                 }
@@ -8653,6 +9050,33 @@ fn normalized_float_bits(value: f64) -> u64 {
     normalized.to_bits()
 }
 
+/// Unscaled decimal `value` rounded half-to-even at `drop` digits from the
+/// right, keeping its scale. Dropping more digits than an `i128` holds
+/// rounds everything to zero.
+fn round_decimal_digits(value: i128, drop: u32) -> Option<i128> {
+    let Ok(shift) = u8::try_from(drop) else {
+        return Some(0);
+    };
+    if shift > fp_types::DECIMAL128_MAX_PRECISION {
+        return Some(0);
+    }
+    let whole = Decimal::new(value, shift)
+        .rescale(0, DecimalRounding::HalfEven)
+        .ok()?;
+    whole.value.checked_mul(10_i128.checked_pow(drop)?)
+}
+
+/// Hash identity of a decimal: trailing zeros are dropped so `1.0` and
+/// `1.00` collide, and integral values that fit `i64` share the Int64 key
+/// of the integer they are `semantic_eq` to.
+fn decimal_key(value: &Decimal) -> Result<i64, (i128, u8)> {
+    let value = value.normalize();
+    match i64::try_from(value.value) {
+        Ok(int) if value.scale == 0 => Ok(int),
+        _ => Err((value.value, value.scale)),
+    }
+}
+
 fn interval_key(interval: &Interval) -> (u64, u64, IntervalClosed) {
     (
         normalized_float_bits(interval.left),
@@ -8673,18 +9097,27 @@ fn interval_key(interval: &Interval) -> (u64, u64, IntervalClosed) {
 enum SetMemberKey<'a> {
     Bool(bool),
     Int64(i64),
+    UInt64(u64),
+    Decimal(i128, u8),
     FloatBits(u64),
     Utf8(&'a str),
     Timedelta64(i64),
     Datetime64(i64),
     Period(i64, PeriodFreq),
     Interval(u64, u64, IntervalClosed),
+    Nested(String),
 }
 
 fn set_member_key(v: &Scalar) -> Option<SetMemberKey<'_>> {
     Some(match v {
         Scalar::Bool(b) => SetMemberKey::Bool(*b),
         Scalar::Int64(i) => SetMemberKey::Int64(*i),
+        Scalar::UInt64(u) => {
+            i64::try_from(*u).map_or(SetMemberKey::UInt64(*u), SetMemberKey::Int64)
+        }
+        Scalar::Decimal(d) => {
+            decimal_key(d).map_or_else(|(v, s)| SetMemberKey::Decimal(v, s), SetMemberKey::Int64)
+        }
         Scalar::Float64(f) => {
             let norm = if *f == 0.0 { 0.0 } else { *f };
             SetMemberKey::FloatBits(norm.to_bits())
@@ -8697,6 +9130,7 @@ fn set_member_key(v: &Scalar) -> Option<SetMemberKey<'_>> {
             let (left, right, closed) = interval_key(v);
             SetMemberKey::Interval(left, right, closed)
         }
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => SetMemberKey::Nested(nested.to_string()),
         Scalar::Null(_) => return None,
     })
 }
@@ -8719,6 +9153,23 @@ pub enum ColumnError {
     DTypeMismatch { left: DType, right: DType },
     #[error("Integers to negative integer powers are not allowed.")]
     NegativeIntegerPower,
+    #[error("can only use the .{accessor} accessor with a {accessor} dtype; found {dtype:?}")]
+    InvalidAccessor {
+        accessor: &'static str,
+        dtype: DType,
+    },
+    #[error("list index {index} is out of bounds for a list of length {len}")]
+    ListIndexOutOfBounds { index: i64, len: usize },
+    #[error("no struct field named {name:?}")]
+    UnknownStructField { name: String },
+    #[error("Already tz-aware, use tz_convert to convert.")]
+    AlreadyTzAware,
+    #[error("Cannot convert tz-naive timestamps, use tz_localize to localize")]
+    TzNaive,
+    #[error(
+        "cannot mix tz-naive and tz-aware datetime-like values: left={left:?}, right={right:?}"
+    )]
+    TzAwareNaiveMismatch { left: DType, right: DType },
     #[error(transparent)]
     Type(#[from] TypeError),
 }
@@ -8819,6 +9270,141 @@ impl SparseColumn {
     }
 }
 
+/// `Series.list` for a `List` column, over its offset-buffer layout.
+///
+/// Obtained from [`Column::list`]. Missing rows stay missing in every
+/// per-row result.
+#[derive(Debug, Clone)]
+pub struct ListAccessor<'a> {
+    column: &'a Column,
+    inner: DType,
+    offsets: Cow<'a, [usize]>,
+    values: Cow<'a, Column>,
+}
+
+impl ListAccessor<'_> {
+    fn span(&self, row: usize) -> std::ops::Range<usize> {
+        self.offsets[row]..self.offsets[row + 1]
+    }
+
+    /// Number of elements per row (`Series.list.len()`). Int64, or
+    /// nullable Int64 when some rows are missing.
+    #[must_use]
+    pub fn len(&self) -> Column {
+        let validity = self.column.validity();
+        let lengths = (0..self.column.len())
+            .map(|row| {
+                if validity.get(row) {
+                    Scalar::Int64(self.span(row).len() as i64)
+                } else {
+                    Scalar::Null(NullKind::Null)
+                }
+            })
+            .collect::<Vec<_>>();
+        let dtype = if self.column.has_nulls() {
+            DType::Int64Nullable
+        } else {
+            DType::Int64
+        };
+        Column::new(dtype, lengths).expect("list lengths are valid Int64 values")
+    }
+
+    /// Element `index` of every row (`Series.list[index]`); negative indices
+    /// count from the end. Like pyarrow, an index past the end of any
+    /// non-missing row is an error rather than a missing value.
+    pub fn get(&self, index: i64) -> Result<Column, ColumnError> {
+        let validity = self.column.validity();
+        let children = self.values.values();
+        let picked = (0..self.column.len())
+            .map(|row| {
+                if !validity.get(row) {
+                    return Ok(Scalar::missing_for_dtype(self.inner));
+                }
+                let span = self.span(row);
+                let len = span.len();
+                let position = if index < 0 {
+                    len.checked_sub(saturating_i64_abs_to_usize(index))
+                } else {
+                    usize::try_from(index)
+                        .ok()
+                        .filter(|&position| position < len)
+                };
+                position
+                    .map(|position| children[span.start + position].clone())
+                    .ok_or(ColumnError::ListIndexOutOfBounds { index, len })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Column::new(self.inner, picked)
+    }
+
+    /// Every element of every row, in order (`Series.list.flatten()`).
+    /// Missing and empty rows contribute nothing.
+    #[must_use]
+    pub fn flatten(&self) -> Column {
+        let (Some(&start), Some(&end)) = (self.offsets.first(), self.offsets.last()) else {
+            return Column::nested_child(self.inner, Vec::new);
+        };
+        self.values.take_contiguous_range(start, end - start)
+    }
+
+    /// One row per element (`Series.explode()`), without a separator: the
+    /// exploded column plus, for each output row, the position of the row it
+    /// came from. Empty and missing lists each produce one missing row.
+    pub fn explode(&self) -> Result<(Vec<usize>, Column), ColumnError> {
+        let validity = self.column.validity();
+        let children = self.values.values();
+        let mut positions = Vec::with_capacity(children.len());
+        let mut exploded = Vec::with_capacity(children.len());
+        for row in 0..self.column.len() {
+            let span = self.span(row);
+            if !validity.get(row) || span.is_empty() {
+                positions.push(row);
+                exploded.push(Scalar::missing_for_dtype(self.inner));
+                continue;
+            }
+            positions.extend(std::iter::repeat_n(row, span.len()));
+            exploded.extend_from_slice(&children[span]);
+        }
+        Ok((positions, Column::new(self.inner, exploded)?))
+    }
+}
+
+/// `Series.struct` for a `Struct` column: per-field child columns.
+///
+/// Obtained from [`Column::r#struct`]. Rows where the struct itself is
+/// missing are missing in every field.
+#[derive(Debug, Clone)]
+pub struct StructAccessor<'a> {
+    dtype: StructType,
+    fields: Cow<'a, [(String, Column)]>,
+}
+
+impl StructAccessor<'_> {
+    /// Field names and dtypes (`Series.struct.dtypes`).
+    #[must_use]
+    pub fn dtypes(&self) -> &'static [StructField] {
+        self.dtype.fields()
+    }
+
+    /// The child column of field `name` (`Series.struct.field(name)`).
+    pub fn field(&self, name: &str) -> Result<Column, ColumnError> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, column)| column.clone())
+            .ok_or_else(|| ColumnError::UnknownStructField {
+                name: name.to_owned(),
+            })
+    }
+
+    /// Every field as its own named column, in field order
+    /// (`Series.struct.explode()`).
+    #[must_use]
+    pub fn explode(&self) -> Vec<(String, Column)> {
+        self.fields.to_vec()
+    }
+}
+
 fn saturating_i64_to_usize(value: i64) -> usize {
     if value <= 0 {
         0
@@ -8923,7 +9509,7 @@ impl Column {
                     data.iter().copied().map(Scalar::Timedelta64).collect(),
                 ))
             }
-            (Some(ColumnData::Datetime64(data)), DType::Datetime64)
+            (Some(ColumnData::Datetime64(data)), DType::Datetime64 | DType::DatetimeTz(_))
                 if data.len() == self.values.len() =>
             {
                 Some(ScalarValues::from_vec(
@@ -8954,11 +9540,29 @@ impl Column {
             | DType::Float64
             | DType::Timedelta64
             | DType::Datetime64
-            | DType::Period => Some(ColumnData::from_scalars(values, dtype)),
+            | DType::DatetimeTz(_)
+            | DType::Period
+            | DType::Decimal { .. }
+            | DType::List(_)
+            | DType::Struct(_) => Some(ColumnData::from_scalars(values, dtype)),
+            dtype if dtype.is_sized_numeric() => Some(ColumnData::from_scalars(values, dtype)),
             _ => None,
         }
     }
 
+    /// Whether `values` is exactly what a sized buffer plus `validity`
+    /// materializes, so the Scalars can be dropped for the narrow backing.
+    fn sized_values_round_trip(dtype: DType, values: &[Scalar], validity: &ValidityMask) -> bool {
+        let missing = Scalar::missing_for_dtype(dtype);
+        values.iter().enumerate().all(|(i, value)| match value {
+            _ if !validity.get(i) => *value == missing,
+            Scalar::Int64(_) => dtype.is_integer() && dtype != DType::UInt64,
+            Scalar::UInt64(_) => dtype == DType::UInt64,
+            Scalar::Float64(_) => dtype == DType::Float32,
+            _ => false,
+        })
+    }
+
     fn normalize_missing_for_dtype(value: Scalar, dtype: DType) -> Scalar {
         match value {
             Scalar::Null(NullKind::NaN) => Scalar::Null(NullKind::NaN),
@@ -8968,6 +9572,13 @@ impl Column {
         }
     }
 
+    /// Child column of a nested column. Values that do not cast to the
+    /// declared child dtype (only possible for hand-built scalars) keep an
+    /// object column rather than failing the infallible typed conversion.
+    fn nested_child(dtype: DType, values: impl Fn() -> Vec<Scalar>) -> Self {
+        Self::new(dtype, values()).unwrap_or_else(|_| Self::from_object_values(values()))
+    }
+
     fn from_object_values(values: Vec<Scalar>) -> Self {
         let validity = ValidityMask::from_values(&values);
         Self {
@@ -9008,7 +9619,7 @@ impl Column {
                     // toward zero. astype pre-truncates via cast_scalar, so this
                     // coercion path only ever sees raw floats from the explicit
                     // constructor. (br-frankenpandas-8nupg)
-                    if matches!(dtype, DType::Int64 | DType::Int64Nullable)
+                    if dtype.is_integer()
                         && let Scalar::Float64(v) = &value
                         && v.is_finite()
                         && v.fract() != 0.0
@@ -9055,6 +9666,12 @@ impl Column {
             (Some(ColumnData::Int64(data)), DType::Int64, true) => {
                 ScalarValues::lazy_all_valid_int64_arc(Arc::clone(data))
             }
+            (Some(data), dtype, _)
+                if dtype.is_sized_numeric()
+                    && Self::sized_values_round_trip(dtype, &coerced, &validity) =>
+            {
+                ScalarValues::lazy_sized_numeric(dtype, data.clone(), validity.clone())
+            }
             _ => ScalarValues::from_vec(coerced),
         };
 
@@ -10108,6 +10725,41 @@ impl Column {
         }
     }
 
+    /// Build a sized numeric column (`Int8`…`UInt64`, `Float32`) directly from
+    /// its native-width buffer, without materializing Scalars. A `Float32` NaN
+    /// is missing like any float. `None` unless `data` is a sized variant as
+    /// long as `validity`.
+    #[must_use]
+    pub fn from_sized_data(data: ColumnData, mut validity: ValidityMask) -> Option<Self> {
+        let dtype = data.sized_dtype()?;
+        if data.len() != validity.len() {
+            return None;
+        }
+        if let ColumnData::Float32(values) = &data {
+            for (i, value) in values.iter().enumerate() {
+                if value.is_nan() {
+                    validity.set(i, false);
+                }
+            }
+        }
+        Some(Self {
+            dtype,
+            values: ScalarValues::lazy_sized_numeric(dtype, data.clone(), validity.clone()),
+            validity,
+            data: Some(data),
+        })
+    }
+
+    /// The native-width buffer behind a sized numeric column, with its
+    /// validity (invalid slots hold 0).
+    #[must_use]
+    pub fn sized_data(&self) -> Option<(&ColumnData, &ValidityMask)> {
+        match &self.data {
+            Some(data) if data.sized_dtype() == Some(self.dtype) => Some((data, &self.validity)),
+            _ => None,
+        }
+    }
+
     /// Datetime64 counterpart of [`Self::from_i64_values_with_validity`]: invalid
     /// slots materialize `Scalar::Null(NullKind::NaT)` (= `missing_for_dtype`),
     /// valid slots `Scalar::Datetime64(data[i])`. All-valid folds to the owned
@@ -11346,6 +11998,10 @@ impl Column {
     /// validated index positions; this mirrors the prior `values()[pos]` index).
     #[must_use]
     pub fn take_positions(&self, positions: &[usize]) -> Self {
+        self.retag_nullable_gather(self.take_positions_untagged(positions))
+    }
+
+    fn take_positions_untagged(&self, positions: &[usize]) -> Self {
         let n = positions.len();
         if self.validity.all() {
             // Zero-copy contiguous-range Float64 view
@@ -12027,6 +12683,10 @@ impl Column {
     /// Panics if the requested range overflows or extends beyond this column.
     #[must_use]
     pub fn take_contiguous_range(&self, start: usize, len: usize) -> Self {
+        self.retag_nullable_gather(self.take_contiguous_range_untagged(start, len))
+    }
+
+    fn take_contiguous_range_untagged(&self, start: usize, len: usize) -> Self {
         let end = start
             .checked_add(len)
             .expect("contiguous range end must not overflow");
@@ -12607,6 +13267,20 @@ impl Column {
         self.validity.count_invalid() > 0
     }
 
+    /// Restore a nullable extension dtype on a gather of `self`.
+    ///
+    /// The typed gather kernels emit numpy-tagged Int64/Bool backings (or
+    /// `Null` for an all-missing result); the stored scalars are identical
+    /// for the nullable variants, so only the tag needs to follow the source.
+    fn retag_nullable_gather(&self, mut gathered: Self) -> Self {
+        if self.dtype.is_nullable()
+            && (gathered.dtype == self.dtype.to_non_nullable() || gathered.dtype == DType::Null)
+        {
+            gathered.dtype = self.dtype;
+        }
+        gathered
+    }
+
     /// Promote the dtype to its nullable variant if the column has nulls.
     ///
     /// For Int64 with nulls → Int64Nullable, Bool with nulls → BoolNullable.
@@ -12977,7 +13651,22 @@ impl Column {
         Self::new(DType::Bool, out)
     }
 
+    /// Gather by optional source positions, filling `None` (or out-of-range)
+    /// slots with the dtype's missing marker.
+    ///
+    /// Nullable extension dtypes (`Int64`/`boolean`) keep their tag even
+    /// though the typed gathers below build plain Int64/Bool backings, so a
+    /// null-introducing reindex or join fill yields `pd.NA` rather than
+    /// drifting to numpy dtypes.
     pub fn reindex_by_positions(&self, positions: &[Option<usize>]) -> Result<Self, ColumnError> {
+        self.reindex_by_positions_untagged(positions)
+            .map(|column| self.retag_nullable_gather(column))
+    }
+
+    fn reindex_by_positions_untagged(
+        &self,
+        positions: &[Option<usize>],
+    ) -> Result<Self, ColumnError> {
         let mut present_positions = Vec::with_capacity(positions.len());
         let mut all_present = true;
         for position in positions {
@@ -13908,6 +14597,17 @@ impl Column {
             });
         }
 
+        // Tz-aware arithmetic runs on the UTC instants; a shifted datetime
+        // keeps the zone of its datetime operand.
+        if let Some((left, naive_right)) = self.tz_aware_operands(right)? {
+            let out = left.binary_numeric(&naive_right, op)?;
+            let zone = self.dtype.tz().or(right.dtype.tz());
+            return Ok(match (out.dtype, zone) {
+                (DType::Datetime64, Some(tz)) => out.with_datetime_dtype(DType::DatetimeTz(tz)),
+                _ => out,
+            });
+        }
+
         // Typed TEMPORAL arithmetic fast path — BEFORE the numeric common_dtype/to_f64
         // machinery, which (a) errors: a temporal scalar is not numeric to `to_f64`, so a
         // present temporal pair hits `Scalar::to_f64()? => Err(NonNumericValue)`; (b)
@@ -13963,6 +14663,15 @@ impl Column {
             });
         }
 
+        // Decimal operands stay exact; a float partner takes the Float64 path
+        // below, like pyarrow's decimal-with-double kernels.
+        if (self.dtype.is_decimal() || right.dtype.is_decimal())
+            && !self.dtype.is_floating()
+            && !right.dtype.is_floating()
+        {
+            return self.binary_decimal(right, op, DecimalRounding::HalfEven);
+        }
+
         let mut out_dtype = common_dtype(self.dtype, right.dtype)?;
         if matches!(out_dtype, DType::Bool) {
             out_dtype = DType::Int64;
@@ -13970,12 +14679,18 @@ impl Column {
         // Div always produces Float64. Pow keeps Int64 for int**int (numpy/pandas
         // semantics: 2 ** 3 -> int64 8, not float), but promotes to Float64 for any
         // float operand. Mod and FloorDiv preserve int if there are no zero divisors.
+        let sized_int_out = out_dtype.is_sized_numeric() && out_dtype.is_integer();
         let int_pow = matches!(op, ArithmeticOp::Pow)
-            && self.dtype == DType::Int64
-            && right.dtype == DType::Int64;
+            && (sized_int_out || (self.dtype == DType::Int64 && right.dtype == DType::Int64));
         if matches!(op, ArithmeticOp::Div | ArithmeticOp::Pow) && !int_pow {
             out_dtype = DType::Float64;
         }
+        if sized_int_out
+            && !matches!(op, ArithmeticOp::Div)
+            && let Some(result) = self.binary_sized_int(right, op, out_dtype)?
+        {
+            return Ok(result);
+        }
 
         // AG-10: Try vectorized path first; fallback to scalar path.
         if let Some(result) = self.try_vectorized_binary(right, op, out_dtype) {
@@ -13984,7 +14699,7 @@ impl Column {
 
         // For Mod/FloorDiv: if vectorized failed (likely due to zero divisors), use Float64
         if matches!(op, ArithmeticOp::Mod | ArithmeticOp::FloorDiv)
-            && matches!(out_dtype, DType::Int64)
+            && (matches!(out_dtype, DType::Int64) || sized_int_out)
         {
             out_dtype = DType::Float64;
         }
@@ -14051,6 +14766,377 @@ impl Column {
         Self::new(out_dtype, values)
     }
 
+    /// Sized integer arithmetic the numpy way: exact over `i128`, then
+    /// wrapped into `out_dtype`'s width (`int8(100) + int8(100) == -56`,
+    /// `uint64(0) - uint64(1) == 2**64 - 1`). Returns `None` when a present
+    /// mod/floordiv divisor is zero, which leaves the integer kernels for the
+    /// float path exactly like Int64.
+    fn binary_sized_int(
+        &self,
+        right: &Self,
+        op: ArithmeticOp,
+        out_dtype: DType,
+    ) -> Result<Option<Self>, ColumnError> {
+        let (Some((left, left_valid)), Some((right_values, right_valid))) =
+            (self.exact_int_operands(), right.exact_int_operands())
+        else {
+            return Ok(None);
+        };
+        let validity = left_valid.and_mask(right_valid);
+        let present_divisors = || {
+            right_values
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| validity.get(i))
+                .map(|(_, &r)| r)
+        };
+        if matches!(op, ArithmeticOp::Mod | ArithmeticOp::FloorDiv)
+            && present_divisors().any(|r| r == 0)
+        {
+            return Ok(None);
+        }
+        if matches!(op, ArithmeticOp::Pow) && present_divisors().any(|r| r < 0) {
+            return Err(ColumnError::NegativeIntegerPower);
+        }
+        let results: Vec<i128> = left
+            .iter()
+            .zip(&right_values)
+            .enumerate()
+            .map(|(i, (&l, &r))| {
+                if !validity.get(i) {
+                    return 0;
+                }
+                match op {
+                    ArithmeticOp::Add => l.wrapping_add(r),
+                    ArithmeticOp::Sub => l.wrapping_sub(r),
+                    ArithmeticOp::Mul => l.wrapping_mul(r),
+                    ArithmeticOp::Pow => wrapping_pow_i128(l, r.unsigned_abs()),
+                    ArithmeticOp::FloorDiv => python_floor_div_i128(l, r),
+                    ArithmeticOp::Mod => python_mod_i128(l, r),
+                    ArithmeticOp::Div => unreachable!("true division is always float"),
+                }
+            })
+            .collect();
+        let Some(data) = ColumnData::wrap_from_i128(out_dtype, &results) else {
+            return Ok(None);
+        };
+        Ok(Self::from_sized_data(data, validity))
+    }
+
+    /// Exact integer operands (widened to `i128`) and validity for an
+    /// integer or bool column, read from the typed buffer when there is one.
+    fn exact_int_operands(&self) -> Option<(Vec<i128>, &ValidityMask)> {
+        if !(self.dtype.is_integer() || matches!(self.dtype, DType::Bool | DType::BoolNullable)) {
+            return None;
+        }
+        if let Some((data, validity)) = self.as_i64_slice_with_validity() {
+            return Some((data.iter().map(|&v| i128::from(v)).collect(), validity));
+        }
+        let widened = match &self.data {
+            Some(data) => data.widen_to_i128(),
+            None => ColumnData::from_scalars(&self.values, self.dtype).widen_to_i128(),
+        }?;
+        Some((widened, &self.validity))
+    }
+
+    /// Exact element-wise arithmetic where either side is `Decimal` (an
+    /// integer or bool partner joins at scale 0). Result dtypes follow the
+    /// SQL decimal rules:
+    ///
+    /// - add / sub: `scale = max(s1, s2)`, `precision = max(p1 - s1, p2 - s2) + scale + 1`
+    /// - mul: `scale = s1 + s2`, `precision = p1 + p2 + 1`
+    /// - div: `scale = max(6, s1 + p2 + 1)`, `precision = p1 - s1 + s2 + scale`
+    ///
+    /// A precision above 38 is capped by giving up scale first (keeping at
+    /// least 6 fractional digits), with `rounding` applied to the dropped
+    /// digits and to every quotient. Values that still overflow raise
+    /// `DecimalOverflow`, and dividing by zero raises
+    /// `DecimalDivisionByZero`, like Python's `decimal` context. Mod, pow and
+    /// floordiv have no exact decimal kernel and are rejected.
+    pub fn binary_decimal(
+        &self,
+        right: &Self,
+        op: ArithmeticOp,
+        rounding: DecimalRounding,
+    ) -> Result<Self, ColumnError> {
+        if self.len() != right.len() {
+            return Err(ColumnError::LengthMismatch {
+                left: self.len(),
+                right: right.len(),
+            });
+        }
+        let incompatible = || {
+            ColumnError::Type(TypeError::IncompatibleDtypes {
+                left: self.dtype,
+                right: right.dtype,
+            })
+        };
+        let ((lp, ls), (rp, rs)) = self
+            .dtype
+            .decimal_parts()
+            .zip(right.dtype.decimal_parts())
+            .ok_or_else(incompatible)?;
+        let (lp, ls, rp, rs) = (u32::from(lp), u32::from(ls), u32::from(rp), u32::from(rs));
+        let (precision, scale) = match op {
+            ArithmeticOp::Add | ArithmeticOp::Sub => {
+                let scale = ls.max(rs);
+                ((lp - ls).max(rp - rs) + scale + 1, scale)
+            }
+            ArithmeticOp::Mul => (lp + rp + 1, ls + rs),
+            ArithmeticOp::Div => {
+                let scale = (ls + rp + 1).max(6);
+                (lp - ls + rs + scale, scale)
+            }
+            ArithmeticOp::Mod | ArithmeticOp::Pow | ArithmeticOp::FloorDiv => {
+                return Err(incompatible());
+            }
+        };
+        let max = u32::from(fp_types::DECIMAL128_MAX_PRECISION);
+        let (precision, scale) = if precision > max {
+            let integral = precision - scale;
+            (max, scale.min(max.saturating_sub(integral).max(6)))
+        } else {
+            (precision, scale)
+        };
+        // Both bounded by 38 above.
+        let (precision, scale) = (precision as u8, scale as u8);
+
+        let (left, left_valid) = self.decimal_operands()?;
+        let (right_values, right_valid) = right.decimal_operands()?;
+        let values = left
+            .iter()
+            .zip(&right_values)
+            .enumerate()
+            .map(|(i, (&l, &r))| {
+                if !left_valid.get(i) || !right_valid.get(i) {
+                    return Ok(Scalar::Null(NullKind::Null));
+                }
+                let (l, r) = (Decimal::new(l, ls as u8), Decimal::new(r, rs as u8));
+                let exact = match op {
+                    ArithmeticOp::Add => l.checked_add(r)?,
+                    ArithmeticOp::Sub => l.checked_sub(r)?,
+                    ArithmeticOp::Mul => l.checked_mul(r)?,
+                    _ => l.checked_div(r, scale, rounding)?,
+                };
+                let value = exact.rescale(scale, rounding)?.check_precision(precision)?;
+                Ok::<_, TypeError>(Scalar::from(value))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(DType::Decimal { precision, scale }, values)
+    }
+
+    /// Unscaled `i128` values and validity of a Decimal, integer or bool
+    /// column, read from the typed Decimal128 backing when present.
+    fn decimal_operands(&self) -> Result<(Vec<i128>, ValidityMask), ColumnError> {
+        if let Some(ColumnData::Decimal128(data, _)) = &self.data {
+            return Ok((data.clone(), self.validity.clone()));
+        }
+        if let ColumnData::Decimal128(data, _) = ColumnData::from_scalars(&self.values, self.dtype)
+        {
+            return Ok((data, self.validity.clone()));
+        }
+        let mut validity = self.validity.clone();
+        let data = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| match value {
+                Scalar::Int64(v) => i128::from(*v),
+                Scalar::UInt64(v) => i128::from(*v),
+                Scalar::Bool(v) => i128::from(*v),
+                _ => {
+                    validity.set(i, false);
+                    0
+                }
+            })
+            .collect();
+        Ok((data, validity))
+    }
+
+    /// The `.list` accessor. Fails for any dtype other than `List`.
+    pub fn list(&self) -> Result<ListAccessor<'_>, ColumnError> {
+        let DType::List(list) = self.dtype else {
+            return Err(ColumnError::InvalidAccessor {
+                accessor: "list",
+                dtype: self.dtype,
+            });
+        };
+        let (offsets, values) = match &self.data {
+            Some(ColumnData::List { offsets, values }) => (
+                Cow::Borrowed(offsets.as_slice()),
+                Cow::Borrowed(values.as_ref()),
+            ),
+            _ => match ColumnData::from_scalars(&self.values, self.dtype) {
+                ColumnData::List { offsets, values } => (Cow::Owned(offsets), Cow::Owned(*values)),
+                _ => unreachable!("List dtype always converts to list data"),
+            },
+        };
+        Ok(ListAccessor {
+            column: self,
+            inner: list.inner(),
+            offsets,
+            values,
+        })
+    }
+
+    /// The `.struct` accessor. Fails for any dtype other than `Struct`.
+    pub fn r#struct(&self) -> Result<StructAccessor<'_>, ColumnError> {
+        let DType::Struct(dtype) = self.dtype else {
+            return Err(ColumnError::InvalidAccessor {
+                accessor: "struct",
+                dtype: self.dtype,
+            });
+        };
+        let fields = match &self.data {
+            Some(ColumnData::Struct { fields, .. }) => Cow::Borrowed(fields.as_slice()),
+            _ => match ColumnData::from_scalars(&self.values, self.dtype) {
+                ColumnData::Struct { fields, .. } => Cow::Owned(fields),
+                _ => unreachable!("Struct dtype always converts to struct data"),
+            },
+        };
+        Ok(StructAccessor { dtype, fields })
+    }
+
+    /// Read a naive datetime column as wall-clock time in `tz`
+    /// (`Series.dt.tz_localize`). DST folds and gaps resolve through the
+    /// given policies.
+    ///
+    /// `None` drops the zone of a tz-aware column and keeps its local wall
+    /// clock. That naive view is what the `.dt` field accessors
+    /// (`hour`, `day`, ...) read, so they are DST-correct.
+    pub fn tz_localize(
+        &self,
+        tz: Option<TimeZone>,
+        ambiguous: TzAmbiguousPolicy,
+        nonexistent: TzNonexistentPolicy,
+    ) -> Result<Self, ColumnError> {
+        let nanos = self.datetime_nanos()?;
+        match (self.dtype, tz) {
+            (DType::Datetime64, Some(tz)) => {
+                let instants = tz.localize(&nanos, ambiguous, nonexistent)?;
+                Self::from_datetime_nanos(DType::DatetimeTz(tz), instants)
+            }
+            (DType::DatetimeTz(zone), None) => Self::from_datetime_nanos(
+                DType::Datetime64,
+                nanos.into_iter().map(|at| zone.to_wall_nanos(at)).collect(),
+            ),
+            (DType::DatetimeTz(_), Some(_)) => Err(ColumnError::AlreadyTzAware),
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// Show a tz-aware column in another zone (`Series.dt.tz_convert`).
+    /// The instants are unchanged; `None` gives naive UTC.
+    pub fn tz_convert(&self, tz: Option<TimeZone>) -> Result<Self, ColumnError> {
+        self.datetime_nanos()?;
+        match self.dtype {
+            DType::DatetimeTz(_) => {
+                Ok(self.with_datetime_dtype(tz.map_or(DType::Datetime64, DType::DatetimeTz)))
+            }
+            _ => Err(ColumnError::TzNaive),
+        }
+    }
+
+    /// Raw nanoseconds of a datetime column, NaT for missing slots.
+    fn datetime_nanos(&self) -> Result<Vec<i64>, ColumnError> {
+        if !self.dtype.is_datetime() {
+            return Err(ColumnError::DTypeMismatch {
+                left: self.dtype,
+                right: DType::Datetime64,
+            });
+        }
+        Ok(self
+            .values
+            .iter()
+            .map(|value| match value {
+                Scalar::Datetime64(at) => *at,
+                _ => Timestamp::NAT,
+            })
+            .collect())
+    }
+
+    fn from_datetime_nanos(dtype: DType, nanos: Vec<i64>) -> Result<Self, ColumnError> {
+        let values = nanos
+            .into_iter()
+            .map(|at| {
+                if at == Timestamp::NAT {
+                    Scalar::Null(NullKind::NaT)
+                } else {
+                    Scalar::Datetime64(at)
+                }
+            })
+            .collect();
+        Self::new(dtype, values)
+    }
+
+    /// The same datetime storage tagged with another datetime dtype.
+    fn with_datetime_dtype(&self, dtype: DType) -> Self {
+        let mut out = self.clone();
+        out.dtype = dtype;
+        out
+    }
+
+    /// Naive UTC view of a tz-aware column, so instant comparisons and
+    /// arithmetic reuse the `datetime64[ns]` kernels. Mixing a tz-aware
+    /// operand with a naive datetime one is an error, as in pandas.
+    fn tz_aware_operands<'a>(
+        &'a self,
+        right: &'a Self,
+    ) -> Result<Option<NaiveOperands<'a>>, ColumnError> {
+        // Typed nanos, so the temporal fast paths apply even with NaT rows.
+        let naive = |column: &'a Self| -> Result<Cow<'a, Self>, ColumnError> {
+            Ok(match column.dtype {
+                DType::DatetimeTz(_) => Cow::Owned(Self::from_datetime64_values_with_validity(
+                    column.datetime_nanos()?,
+                    column.validity.clone(),
+                )),
+                _ => Cow::Borrowed(column),
+            })
+        };
+        match (self.dtype, right.dtype) {
+            (DType::DatetimeTz(_), DType::Datetime64)
+            | (DType::Datetime64, DType::DatetimeTz(_)) => Err(ColumnError::TzAwareNaiveMismatch {
+                left: self.dtype,
+                right: right.dtype,
+            }),
+            (DType::DatetimeTz(_), _) | (_, DType::DatetimeTz(_)) => {
+                Ok(Some((naive(self)?, naive(right)?)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Rescale a Decimal column to `scale` fractional digits, rounding by
+    /// `rounding` (`Decimal.quantize`). The integer part keeps its digits,
+    /// plus one when rounding can carry into it.
+    pub fn quantize(&self, scale: u8, rounding: DecimalRounding) -> Result<Self, ColumnError> {
+        let DType::Decimal {
+            precision,
+            scale: current,
+        } = self.dtype
+        else {
+            return Err(ColumnError::Type(TypeError::InvalidCast {
+                from: self.dtype,
+                to: DType::Decimal {
+                    precision: fp_types::DECIMAL128_MAX_PRECISION,
+                    scale,
+                },
+            }));
+        };
+        let carry = u8::from(scale < current);
+        let precision =
+            (precision - current + scale + carry).min(fp_types::DECIMAL128_MAX_PRECISION);
+        let values = self
+            .values
+            .iter()
+            .map(|value| match value {
+                Scalar::Decimal(d) => Ok(Scalar::from(d.rescale(scale, rounding)?)),
+                other => Ok::<_, TypeError>(other.clone()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(DType::Decimal { precision, scale }, values)
+    }
+
     /// Element-wise addition, matching `pd.Series.add()`.
     pub fn add(&self, right: &Self) -> Result<Self, ColumnError> {
         self.binary_numeric(right, ArithmeticOp::Add)
@@ -15288,6 +16374,27 @@ impl Column {
                 right: right.len(),
             });
         }
+        // Tz-aware values compare as instants, whatever their zones.
+        if let Some((left, naive_right)) = self.tz_aware_operands(right)? {
+            return left.binary_comparison(&naive_right, op);
+        }
+        // Sized integers compare exactly over i128; the f64 arms below would
+        // merge `uint64` values above 2^53.
+        if (self.dtype.is_sized_numeric() || right.dtype.is_sized_numeric())
+            && let (Some((l, lv)), Some((r, rv))) =
+                (self.exact_int_operands(), right.exact_int_operands())
+        {
+            let zip = || l.iter().zip(&r);
+            let bools: Vec<bool> = match op {
+                ComparisonOp::Gt => zip().map(|(a, b)| a > b).collect(),
+                ComparisonOp::Lt => zip().map(|(a, b)| a < b).collect(),
+                ComparisonOp::Eq => zip().map(|(a, b)| a == b).collect(),
+                ComparisonOp::Ne => zip().map(|(a, b)| a != b).collect(),
+                ComparisonOp::Ge => zip().map(|(a, b)| a >= b).collect(),
+                ComparisonOp::Le => zip().map(|(a, b)| a <= b).collect(),
+            };
+            return Ok(Self::from_bool_values_with_validity(bools, lv.and_mask(rv)));
+        }
 
         // Typed fast path: both operands are all-valid contiguous Float64,
         // Int64, or Bool, so compare over the buffers and build the Bool result via
@@ -15613,6 +16720,30 @@ impl Column {
         self.binary_comparison(right, ComparisonOp::Ge)
     }
 
+    /// Element-wise arithmetic against a Python scalar, matching `series <op> 1`.
+    ///
+    /// The scalar is weak under NEP 50: it adopts this column's dtype when it
+    /// fits, so `uint64 - 1` stays `uint64` where a column of `int64` ones
+    /// would promote to `float64`.
+    pub fn binary_numeric_scalar(
+        &self,
+        scalar: &Scalar,
+        op: ArithmeticOp,
+    ) -> Result<Self, ColumnError> {
+        let dtype = if scalar.is_missing() {
+            self.dtype
+        } else {
+            common_dtype_with_scalar(self.dtype, scalar)?
+        };
+        let broadcast = if dtype == self.dtype {
+            dtype
+        } else {
+            scalar.dtype()
+        };
+        let right = Self::new(broadcast, vec![scalar.clone(); self.len()])?;
+        self.binary_numeric(&right, op)
+    }
+
     /// Compare every element against a scalar value, producing a `Bool`-typed column.
     ///
     /// Missing values in the column propagate as missing in the result.
@@ -15622,6 +16753,12 @@ impl Column {
             let values = vec![Scalar::Null(NullKind::Null); self.len()];
             return Self::new(DType::Bool, values);
         }
+        // A datetime scalar is a UTC instant; compare it as one.
+        if self.dtype.is_datetime_tz() {
+            return self
+                .with_datetime_dtype(DType::Datetime64)
+                .compare_scalar(scalar, op);
+        }
 
         // Typed fast path (br-frankenpandas-2kpwa): when self is an all-valid
         // contiguous numeric buffer, compare against the scalar directly over the
@@ -16495,14 +17632,39 @@ impl Column {
 
     /// Concatenate `other` onto `self`, preserving dtype.
     ///
-    /// Returns `ColumnError::DTypeMismatch` when `other.dtype()` differs
-    /// from `self.dtype()`.
+    /// A nullable `Int64Nullable`/`BoolNullable` side absorbs its numpy twin
+    /// or an all-missing `Null` column and keeps the masked dtype, as
+    /// `pd.concat` does for `Int64` + `int64`. Any other dtype difference
+    /// returns `ColumnError::DTypeMismatch`.
     pub fn concat(&self, other: &Self) -> Result<Self, ColumnError> {
         if self.dtype != other.dtype {
-            return Err(ColumnError::DTypeMismatch {
-                left: self.dtype,
-                right: other.dtype,
-            });
+            let absorbs = |nullable: DType, numpy: DType| {
+                nullable.is_nullable()
+                    && (numpy == nullable.to_non_nullable() || numpy == DType::Null)
+            };
+            let dtype = if absorbs(self.dtype, other.dtype) {
+                self.dtype
+            } else if absorbs(other.dtype, self.dtype) {
+                other.dtype
+            } else {
+                return Err(ColumnError::DTypeMismatch {
+                    left: self.dtype,
+                    right: other.dtype,
+                });
+            };
+            let values = self
+                .values
+                .iter()
+                .chain(other.values.iter())
+                .map(|value| {
+                    if value.is_missing() {
+                        Scalar::missing_for_dtype(dtype)
+                    } else {
+                        value.clone()
+                    }
+                })
+                .collect();
+            return Self::new(dtype, values);
         }
         // perf: typed buffer concat for the ubiquitous all-valid Int64/Float64
         // case — extend the contiguous i64/f64 buffers directly instead of cloning
@@ -17132,6 +18294,14 @@ impl Column {
     /// Empty column returns 0.0 (matching pandas).
     #[must_use]
     pub fn sum(&self) -> Scalar {
+        // Decimal: exact fold; an empty or all-missing column sums to zero at
+        // the column scale rather than nansum's Float64(0.0).
+        if let DType::Decimal { scale, .. } = self.dtype {
+            return match nansum(&self.values) {
+                Scalar::Float64(_) => Scalar::from(Decimal::new(0, scale)),
+                other => other,
+            };
+        }
         // Typed reduction: an all-valid Float64 column sums straight over its
         // contiguous buffer instead of materializing/iterating a Vec<Scalar>.
         // Bit-identical to nansum's Float64 arm: a sequential left-fold seeded
@@ -19121,12 +20291,15 @@ impl Column {
         enum Key<'a> {
             Bool(bool),
             Int64(i64),
+            UInt64(u64),
+            Decimal(i128, u8),
             FloatBits(u64),
             Utf8(&'a str),
             Timedelta64(i64),
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Nested(String),
         }
         let mut seen: FxHashSet<Key<'_>> = FxHashSet::default();
         for v in &self.values {
@@ -19136,6 +20309,10 @@ impl Column {
             let key = match v {
                 Scalar::Bool(b) => Key::Bool(*b),
                 Scalar::Int64(i) => Key::Int64(*i),
+                Scalar::UInt64(u) => i64::try_from(*u).map_or(Key::UInt64(*u), Key::Int64),
+                Scalar::Decimal(d) => {
+                    decimal_key(d).map_or_else(|(v, s)| Key::Decimal(v, s), Key::Int64)
+                }
                 Scalar::Float64(f) => {
                     let norm = if *f == 0.0 { 0.0 } else { *f };
                     Key::FloatBits(norm.to_bits())
@@ -19148,6 +20325,7 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested.to_string()),
                 Scalar::Null(_) => continue,
             };
             if !seen.insert(key) {
@@ -20231,12 +21409,15 @@ impl Column {
         enum Key<'a> {
             Bool(bool),
             Int64(i64),
+            UInt64(u64),
+            Decimal(i128, u8),
             FloatBits(u64),
             Utf8(&'a str),
             Timedelta64(i64),
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Nested(String),
         }
         fn key_of(v: &Scalar) -> Option<Key<'_>> {
             if v.is_missing() {
@@ -20245,6 +21426,10 @@ impl Column {
             Some(match v {
                 Scalar::Bool(b) => Key::Bool(*b),
                 Scalar::Int64(i) => Key::Int64(*i),
+                Scalar::UInt64(u) => i64::try_from(*u).map_or(Key::UInt64(*u), Key::Int64),
+                Scalar::Decimal(d) => {
+                    decimal_key(d).map_or_else(|(v, s)| Key::Decimal(v, s), Key::Int64)
+                }
                 Scalar::Float64(f) => {
                     let norm = if *f == 0.0 { 0.0 } else { *f };
                     Key::FloatBits(norm.to_bits())
@@ -20257,6 +21442,7 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested.to_string()),
                 Scalar::Null(_) => return None,
             })
         }
@@ -20293,13 +21479,15 @@ impl Column {
     /// Matches `pd.Series.memory_usage(deep=...)`. When `deep` is true
     /// and the column contains Utf8 values, each string's byte length
     /// is counted; otherwise a fixed per-element width is used
-    /// (8 bytes for numeric/timedelta, 1 for Bool, pointer-sized for
+    /// (8 bytes for Int64/Float64/timedelta, the declared width for
+    /// narrow/unsigned ints and Float32, 1 for Bool, pointer-sized for
     /// Utf8, 0 for Null). The ValidityMask is counted separately.
     #[must_use]
     pub fn memory_usage(&self, deep: bool) -> usize {
         let element_bytes = match self.dtype {
             DType::Bool => 1,
             DType::Int64 | DType::Float64 | DType::Timedelta64 => 8,
+            dtype if dtype.is_sized_numeric() => dtype.itemsize(),
             DType::Utf8 => std::mem::size_of::<usize>(),
             _ => 0,
         };
@@ -20329,15 +21517,26 @@ impl Column {
     /// Return the size in bytes of a single element.
     ///
     /// Matches `pd.Series.dtype.itemsize`. Returns 8 for Int64/Float64/Datetime64/Timedelta64,
-    /// 1 for Bool, and an estimate for variable-length types.
+    /// 1 for Bool, the declared width for narrow/unsigned ints and Float32, and
+    /// an estimate for variable-length types.
     #[must_use]
     pub fn itemsize(&self) -> usize {
         match self.dtype() {
             DType::Bool | DType::BoolNullable => 1,
+            dtype @ (DType::Int8
+            | DType::Int16
+            | DType::Int32
+            | DType::UInt8
+            | DType::UInt16
+            | DType::UInt32
+            | DType::UInt64
+            | DType::Float32
+            | DType::Decimal { .. }) => dtype.itemsize(),
             DType::Int64
             | DType::Int64Nullable
             | DType::Float64
             | DType::Datetime64
+            | DType::DatetimeTz(_)
             | DType::Timedelta64
             | DType::Period => 8,
             DType::Utf8 => {
@@ -20347,7 +21546,12 @@ impl Column {
                     self.memory_usage(true) / self.values.len()
                 }
             }
-            DType::Null | DType::Categorical | DType::Interval | DType::Sparse => 8,
+            DType::Null
+            | DType::Categorical
+            | DType::Interval
+            | DType::Sparse
+            | DType::List(_)
+            | DType::Struct(_) => 8,
         }
     }
 
@@ -20977,12 +22181,16 @@ impl Column {
             let truthy = match v {
                 Scalar::Bool(b) => *b,
                 Scalar::Int64(x) => *x != 0,
+                Scalar::UInt64(x) => *x != 0,
+                Scalar::Decimal(x) => x.value != 0,
                 Scalar::Float64(x) => *x != 0.0 && !x.is_nan(),
                 Scalar::Utf8(s) => !s.is_empty(),
                 Scalar::Timedelta64(x) => *x != 0,
                 Scalar::Datetime64(x) => *x != Timestamp::NAT,
                 Scalar::Period(p) => p.ordinal != i64::MIN,
                 Scalar::Interval(_) => true,
+                Scalar::List(items) => !items.is_empty(),
+                Scalar::Struct(fields) => !fields.is_empty(),
                 Scalar::Null(_) => false,
             };
             if truthy {
@@ -22330,6 +23538,19 @@ impl Column {
         Self::new(target, out)
     }
 
+    /// Shrink a numeric column to the smallest dtype of the requested family.
+    ///
+    /// Matches the `downcast=` step of `pd.to_numeric`: the target comes from
+    /// `fp_types::downcast_numeric_dtype`, and when no candidate fits (or the
+    /// column is not numeric) the column is returned unchanged rather than
+    /// raising, exactly as pandas keeps the input dtype.
+    pub fn downcast_numeric(&self, downcast: NumericDowncast) -> Result<Self, ColumnError> {
+        match downcast_numeric_dtype(self.dtype, &self.values, downcast) {
+            Some(target) if target != self.dtype => self.astype(target),
+            _ => Ok(self.clone()),
+        }
+    }
+
     /// Return the `n` smallest values with explicit keep policy for
     /// ties.
     ///
@@ -23369,12 +24590,15 @@ impl Column {
             Null,
             Bool(bool),
             Int64(i64),
+            UInt64(u64),
+            Decimal(i128, u8),
             FloatBits(u64),
             Utf8(&'a str),
             Timedelta64(i64),
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Nested(String),
         }
         fn key_of(v: &Scalar) -> Key<'_> {
             if v.is_missing() {
@@ -23383,6 +24607,10 @@ impl Column {
             match v {
                 Scalar::Bool(b) => Key::Bool(*b),
                 Scalar::Int64(i) => Key::Int64(*i),
+                Scalar::UInt64(u) => i64::try_from(*u).map_or(Key::UInt64(*u), Key::Int64),
+                Scalar::Decimal(d) => {
+                    decimal_key(d).map_or_else(|(v, s)| Key::Decimal(v, s), Key::Int64)
+                }
                 Scalar::Float64(f) => {
                     let norm = if *f == 0.0 { 0.0 } else { *f };
                     Key::FloatBits(norm.to_bits())
@@ -23395,6 +24623,7 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested.to_string()),
                 Scalar::Null(_) => Key::Null,
             }
         }
@@ -23837,22 +25066,31 @@ impl Column {
         // use_na_sentinel=false branch separately so multiple null
         // kinds collapse to the same code (matches the existing
         // is_missing-based check).
-        #[derive(Hash, PartialEq, Eq, Clone, Copy)]
+        #[derive(Hash, PartialEq, Eq, Clone)]
         enum LocalKey<'a> {
             Bool(bool),
             Int64(i64),
+            UInt64(u64),
+            Decimal(i128, u8),
             FloatBits(u64),
             Utf8(&'a str),
             Timedelta64(i64),
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Nested(String),
         }
         fn key_of(s: &Scalar) -> Option<LocalKey<'_>> {
             match s {
                 Scalar::Null(_) => None,
                 Scalar::Bool(b) => Some(LocalKey::Bool(*b)),
                 Scalar::Int64(i) => Some(LocalKey::Int64(*i)),
+                Scalar::UInt64(u) => {
+                    Some(i64::try_from(*u).map_or(LocalKey::UInt64(*u), LocalKey::Int64))
+                }
+                Scalar::Decimal(d) => Some(
+                    decimal_key(d).map_or_else(|(v, s)| LocalKey::Decimal(v, s), LocalKey::Int64),
+                ),
                 Scalar::Float64(f) => {
                     if f.is_nan() {
                         None
@@ -23887,6 +25125,9 @@ impl Column {
                     let (left, right, closed) = interval_key(interval);
                     Some(LocalKey::Interval(left, right, closed))
                 }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+                    Some(LocalKey::Nested(nested.to_string()))
+                }
             }
         }
 
@@ -26160,6 +27401,13 @@ impl Column {
             return Ok(self.clone());
         }
         let abs = periods.unsigned_abs() as usize;
+        // A masked column vacates to `pd.NA`, whatever missing marker the
+        // caller passed; numpy dtypes keep the caller's fill as-is.
+        let fill = if self.dtype.is_nullable() && fill.is_missing() {
+            Scalar::missing_for_dtype(self.dtype)
+        } else {
+            fill
+        };
         // Typed all-valid-Float64 fast path with a MISSING fill (the default
         // shift, which vacates slots to NaN/missing): build the output f64
         // buffer directly — NaN in the vacated slots, the contiguous source run
@@ -26366,6 +27614,33 @@ impl Column {
         if matches!(self.dtype, DType::Bool) || (self.dtype == DType::Int64 && decimals >= 0) {
             return Ok(self.clone());
         }
+        // Decimal rounds half-to-even in place and keeps its dtype, like
+        // pyarrow's `round` on decimal128.
+        if let DType::Decimal { scale, .. } = self.dtype {
+            let drop = i32::from(scale) - decimals;
+            if drop <= 0 {
+                return Ok(self.clone());
+            }
+            let out = self
+                .values
+                .iter()
+                .map(|v| match v {
+                    Scalar::Decimal(d) => round_decimal_digits(d.value, drop.unsigned_abs())
+                        .map_or_else(
+                            || {
+                                Err(TypeError::DecimalOverflow {
+                                    value: d.to_string(),
+                                    precision: fp_types::DECIMAL128_MAX_PRECISION,
+                                    scale,
+                                })
+                            },
+                            |value| Ok(Scalar::from(Decimal::new(value, scale))),
+                        ),
+                    other => Ok(other.clone()),
+                })
+                .collect::<Result<Vec<_>, TypeError>>()?;
+            return Self::new(self.dtype, out);
+        }
         if self.dtype == DType::Int64 {
             let out = self
                 .values
@@ -26471,12 +27746,15 @@ impl Column {
         enum Key<'a> {
             Bool(bool),
             Int64(i64),
+            UInt64(u64),
+            Decimal(i128, u8),
             FloatBits(u64),
             Utf8(&'a str),
             Timedelta64(i64),
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Nested(String),
         }
         fn key_of(v: &Scalar) -> Option<Key<'_>> {
             if v.is_missing() {
@@ -26485,6 +27763,10 @@ impl Column {
             Some(match v {
                 Scalar::Bool(b) => Key::Bool(*b),
                 Scalar::Int64(i) => Key::Int64(*i),
+                Scalar::UInt64(u) => i64::try_from(*u).map_or(Key::UInt64(*u), Key::Int64),
+                Scalar::Decimal(d) => {
+                    decimal_key(d).map_or_else(|(v, s)| Key::Decimal(v, s), Key::Int64)
+                }
                 Scalar::Float64(f) => {
                     let norm = if *f == 0.0 { 0.0 } else { *f };
                     Key::FloatBits(norm.to_bits())
@@ -26497,6 +27779,7 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested.to_string()),
                 Scalar::Null(_) => return None,
             })
         }
@@ -26828,12 +28111,15 @@ impl Column {
         enum Key<'a> {
             Bool(bool),
             Int64(i64),
+            UInt64(u64),
+            Decimal(i128, u8),
             FloatBits(u64),
             Utf8(&'a str),
             Timedelta64(i64),
             Datetime64(i64),
             Period(i64),
             Interval(u64, u64, IntervalClosed),
+            Nested(String),
         }
 
         let mut seen: FxHashSet<Key<'_>> = FxHashSet::default();
@@ -26845,6 +28131,10 @@ impl Column {
             let key = match v {
                 Scalar::Bool(b) => Key::Bool(*b),
                 Scalar::Int64(i) => Key::Int64(*i),
+                Scalar::UInt64(u) => i64::try_from(*u).map_or(Key::UInt64(*u), Key::Int64),
+                Scalar::Decimal(d) => {
+                    decimal_key(d).map_or_else(|(v, s)| Key::Decimal(v, s), Key::Int64)
+                }
                 Scalar::Float64(f) => {
                     let norm = if *f == 0.0 { 0.0 } else { *f };
                     Key::FloatBits(norm.to_bits())
@@ -26857,6 +28147,7 @@ impl Column {
                     let (left, right, closed) = interval_key(v);
                     Key::Interval(left, right, closed)
                 }
+                nested @ (Scalar::List(_) | Scalar::Struct(_)) => Key::Nested(nested.to_string()),
                 Scalar::Null(_) => continue,
             };
             if seen.insert(key) {
@@ -27586,7 +28877,8 @@ mod tests {
     use std::sync::Arc;
 
     use fp_types::{
-        DType, Interval, IntervalClosed, NullKind, Period, PeriodFreq, Scalar, SparseDType,
+        DType, Decimal, DecimalRounding, Interval, IntervalClosed, NullKind, NumericDowncast,
+        Period, PeriodFreq, Scalar, SparseDType,
     };
 
     use super::{
@@ -47574,6 +48866,643 @@ mod tests {
         assert_eq!(changed.values()[0], Scalar::Int64(42));
     }
 
+    #[test]
+    fn sized_numeric_columns_range_check_gather_and_downcast() {
+        let narrow = Column::new(
+            DType::Int8,
+            vec![Scalar::Int64(-3), Scalar::Int64(100), Scalar::Int64(7)],
+        )
+        .unwrap();
+        assert_eq!(narrow.dtype(), DType::Int8);
+        assert_eq!(narrow.itemsize(), 1);
+        assert_eq!(narrow.take_positions(&[2, 0]).dtype(), DType::Int8);
+        // Like Int64, the column layer keeps the dtype and marks the gap
+        // missing; float promotion is left to the frame-level introducer.
+        let reindexed = narrow.reindex_by_positions(&[Some(1), None]).unwrap();
+        assert_eq!(reindexed.dtype(), DType::Int8);
+        assert_eq!(
+            reindexed.values(),
+            &[Scalar::Int64(100), Scalar::Null(NullKind::Null)]
+        );
+
+        let err = Column::new(DType::Int8, vec![Scalar::Int64(300)]).expect_err("overflow");
+        assert!(matches!(
+            err,
+            ColumnError::Type(fp_types::TypeError::IntegerOutOfBounds { value: 300, .. })
+        ));
+        assert!(Column::new(DType::UInt16, vec![Scalar::Float64(1.5)]).is_err());
+
+        let wide = Column::new(
+            DType::UInt64,
+            vec![Scalar::UInt64(u64::MAX), Scalar::Int64(1)],
+        )
+        .unwrap();
+        assert_eq!(wide.values()[0], Scalar::UInt64(u64::MAX));
+        assert_eq!(wide.values()[1], Scalar::UInt64(1));
+
+        let floats = Column::from_values(vec![Scalar::Float64(0.1), Scalar::Float64(2.5)]).unwrap();
+        let single = floats.astype(DType::Float32).unwrap();
+        assert_eq!(single.dtype(), DType::Float32);
+        assert_eq!(single.values()[0], Scalar::Float64(f64::from(0.1_f32)));
+
+        let ints = Column::from_values(vec![Scalar::Int64(1), Scalar::Int64(40_000)]).unwrap();
+        let signed = ints.downcast_numeric(NumericDowncast::Integer).unwrap();
+        assert_eq!(signed.dtype(), DType::Int32);
+        assert_eq!(signed.values(), ints.values());
+        assert_eq!(
+            ints.downcast_numeric(NumericDowncast::Unsigned)
+                .unwrap()
+                .dtype(),
+            DType::UInt16
+        );
+        let negative = Column::from_values(vec![Scalar::Int64(-1)]).unwrap();
+        assert_eq!(
+            negative
+                .downcast_numeric(NumericDowncast::Unsigned)
+                .unwrap()
+                .dtype(),
+            DType::Int64
+        );
+        assert_eq!(
+            floats
+                .downcast_numeric(NumericDowncast::Float)
+                .unwrap()
+                .dtype(),
+            DType::Float32
+        );
+    }
+
+    #[test]
+    fn uint64_columns_stay_exact_near_u64_max() {
+        let left = Column::new(
+            DType::UInt64,
+            vec![Scalar::UInt64(u64::MAX - 1), Scalar::UInt64((1 << 53) | 1)],
+        )
+        .unwrap();
+        let right = Column::new(
+            DType::UInt64,
+            vec![Scalar::UInt64(u64::MAX - 2), Scalar::UInt64(1 << 53)],
+        )
+        .unwrap();
+        let (data, _) = left.sized_data().expect("uint64 keeps a typed buffer");
+        assert!(matches!(data, ColumnData::UInt64(_)));
+
+        assert_eq!(
+            left.eq(&right).unwrap().values(),
+            &[Scalar::Bool(false), Scalar::Bool(false)]
+        );
+        let diff = left.sub(&right).unwrap();
+        assert_eq!(diff.dtype(), DType::UInt64);
+        assert_eq!(diff.values(), &[Scalar::UInt64(1), Scalar::UInt64(1)]);
+        // numpy wraps uint64 addition modulo 2^64.
+        assert_eq!(
+            left.add(&right).unwrap().values()[0],
+            Scalar::UInt64(u64::MAX - 4)
+        );
+        let zero = Column::new(DType::UInt64, vec![Scalar::UInt64(0)]).unwrap();
+        let one = Column::new(DType::UInt64, vec![Scalar::UInt64(1)]).unwrap();
+        assert_eq!(
+            zero.sub(&one).unwrap().values(),
+            &[Scalar::UInt64(u64::MAX)]
+        );
+
+        let unsorted = Column::new(
+            DType::UInt64,
+            vec![
+                Scalar::UInt64(u64::MAX),
+                Scalar::UInt64(u64::MAX - 1),
+                Scalar::Null(NullKind::Null),
+            ],
+        )
+        .unwrap();
+        assert_eq!(unsorted.argsort(), vec![1, 0, 2]);
+        assert_eq!(
+            unsorted.sort_values(true).unwrap().values()[..2],
+            [Scalar::UInt64(u64::MAX - 1), Scalar::UInt64(u64::MAX)]
+        );
+        assert_eq!(unsorted.min(), Scalar::UInt64(u64::MAX - 1));
+        assert_eq!(unsorted.max(), Scalar::UInt64(u64::MAX));
+
+        // uint64 with an int64 array has no common integer type in numpy.
+        let signed = Column::from_values(vec![Scalar::Int64(1), Scalar::Int64(2)]).unwrap();
+        assert_eq!(left.add(&signed).unwrap().dtype(), DType::Float64);
+    }
+
+    #[test]
+    fn sized_integer_arithmetic_wraps_like_numpy() {
+        let narrow = Column::new(DType::Int8, vec![Scalar::Int64(100)]).unwrap();
+        assert_eq!(narrow.itemsize(), 1);
+        let (data, _) = narrow.sized_data().expect("int8 keeps a one-byte buffer");
+        assert_eq!(data.sized_dtype(), Some(DType::Int8));
+
+        // One byte per row plus the packed validity byte.
+        assert_eq!(narrow.memory_usage(false), 2);
+
+        let sum = narrow.add(&narrow).unwrap();
+        assert_eq!(sum.dtype(), DType::Int8);
+        assert_eq!(sum.values(), &[Scalar::Int64(-56)]);
+
+        // A present zero divisor leaves the integer kernel; numpy's inf/nan
+        // result needs a float column.
+        let zero = Column::new(DType::Int8, vec![Scalar::Int64(0)]).unwrap();
+        assert_eq!(
+            narrow
+                .binary_numeric(&zero, ArithmeticOp::Mod)
+                .unwrap()
+                .dtype(),
+            DType::Float64
+        );
+
+        let built = Column::from_sized_data(
+            ColumnData::UInt8(Arc::from(vec![1_u8, 255])),
+            ValidityMask::from_values(&[Scalar::Int64(1), Scalar::Null(NullKind::Null)]),
+        )
+        .unwrap();
+        assert_eq!(built.dtype(), DType::UInt8);
+        assert_eq!(
+            built.values(),
+            &[Scalar::Int64(1), Scalar::Null(NullKind::Null)]
+        );
+    }
+
+    #[test]
+    fn python_scalar_operands_are_weak_under_nep50() {
+        let wide = Column::new(
+            DType::UInt64,
+            vec![Scalar::UInt64(u64::MAX), Scalar::UInt64(0)],
+        )
+        .unwrap();
+        let shifted = wide
+            .binary_numeric_scalar(&Scalar::Int64(1), ArithmeticOp::Sub)
+            .unwrap();
+        assert_eq!(shifted.dtype(), DType::UInt64);
+        assert_eq!(
+            shifted.values(),
+            &[Scalar::UInt64(u64::MAX - 1), Scalar::UInt64(u64::MAX)]
+        );
+        assert!(matches!(
+            wide.binary_numeric_scalar(&Scalar::Int64(-1), ArithmeticOp::Add),
+            Err(ColumnError::Type(fp_types::TypeError::IntegerOutOfBounds {
+                value: -1,
+                ..
+            }))
+        ));
+        assert_eq!(
+            wide.binary_numeric_scalar(&Scalar::Float64(1.5), ArithmeticOp::Mul)
+                .unwrap()
+                .dtype(),
+            DType::Float64
+        );
+
+        let narrow = Column::new(DType::Int8, vec![Scalar::Int64(100)]).unwrap();
+        let wrapped = narrow
+            .binary_numeric_scalar(&Scalar::Int64(100), ArithmeticOp::Add)
+            .unwrap();
+        assert_eq!(wrapped.dtype(), DType::Int8);
+        assert_eq!(wrapped.values(), &[Scalar::Int64(-56)]);
+
+        let single = Column::new(DType::Float32, vec![Scalar::Float64(1.5)]).unwrap();
+        assert_eq!(
+            single
+                .binary_numeric_scalar(&Scalar::Int64(1), ArithmeticOp::Add)
+                .unwrap()
+                .dtype(),
+            DType::Float32
+        );
+    }
+
+    #[test]
+    fn decimal_columns_do_exact_arithmetic_with_sql_result_dtypes() {
+        let dec = |s: &str| Scalar::from(s.parse::<Decimal>().unwrap());
+        let prices = Column::new(
+            DType::Decimal {
+                precision: 10,
+                scale: 2,
+            },
+            vec![dec("19.99"), Scalar::Null(NullKind::Null), dec("0.1")],
+        )
+        .unwrap();
+        assert!(matches!(prices.data, Some(ColumnData::Decimal128(_, 2))));
+        // Values are rescaled onto the column scale.
+        assert_eq!(prices.values()[2], dec("0.10"));
+        assert_eq!(prices.itemsize(), 16);
+
+        let rates = Column::new(
+            DType::Decimal {
+                precision: 5,
+                scale: 4,
+            },
+            vec![dec("0.0825"), dec("1"), dec("3")],
+        )
+        .unwrap();
+        let sum = prices.add(&rates).unwrap();
+        assert_eq!(
+            sum.dtype(),
+            DType::Decimal {
+                precision: 13,
+                scale: 4
+            }
+        );
+        assert_eq!(
+            sum.values(),
+            &[dec("20.0725"), Scalar::Null(NullKind::Null), dec("3.1000")]
+        );
+        let product = prices.mul(&rates).unwrap();
+        assert_eq!(
+            product.dtype(),
+            DType::Decimal {
+                precision: 16,
+                scale: 6
+            }
+        );
+        assert_eq!(product.values()[0], dec("1.649175"));
+        let quotient = prices.div(&rates).unwrap();
+        assert_eq!(
+            quotient.dtype(),
+            DType::Decimal {
+                precision: 20,
+                scale: 8
+            }
+        );
+        assert_eq!(quotient.values()[2], dec("0.03333333"));
+
+        // Integers join at scale 0; floats fall back to Float64.
+        let ints = Column::from_values(vec![Scalar::Int64(1), Scalar::Int64(2), Scalar::Int64(3)])
+            .unwrap();
+        assert_eq!(prices.sub(&ints).unwrap().values()[0], dec("18.99"));
+        let floats = Column::from_values(vec![Scalar::Float64(0.5); 3]).unwrap();
+        assert_eq!(prices.add(&floats).unwrap().dtype(), DType::Float64);
+        assert!(prices.r#mod(&ints).is_err());
+
+        let zero = Column::from_values(vec![Scalar::Int64(0); 3]).unwrap();
+        assert!(matches!(
+            prices.div(&zero),
+            Err(ColumnError::Type(
+                fp_types::TypeError::DecimalDivisionByZero
+            ))
+        ));
+        let wide = Column::new(
+            DType::Decimal {
+                precision: 38,
+                scale: 0,
+            },
+            vec![Scalar::from(Decimal::new(10_i128.pow(37) * 9, 0))],
+        )
+        .unwrap();
+        assert!(matches!(
+            wide.add(&wide),
+            Err(ColumnError::Type(
+                fp_types::TypeError::DecimalOverflow { .. }
+            ))
+        ));
+
+        let gt = prices.gt(&ints).unwrap();
+        assert_eq!(gt.values()[0], Scalar::Bool(true));
+        assert_eq!(gt.values()[2], Scalar::Bool(false));
+
+        assert_eq!(prices.sum(), dec("20.09"));
+        assert_eq!(prices.mean(), dec("10.04"));
+        assert_eq!(
+            prices.take_positions(&[1]).sum(),
+            Scalar::from(Decimal::new(0, 2))
+        );
+
+        let rounded = prices.round(1).unwrap();
+        assert_eq!(rounded.dtype(), prices.dtype());
+        assert_eq!(rounded.values()[0], dec("20.00"));
+        let quantized = prices.quantize(0, DecimalRounding::Floor).unwrap();
+        assert_eq!(
+            quantized.dtype(),
+            DType::Decimal {
+                precision: 9,
+                scale: 0
+            }
+        );
+        assert_eq!(quantized.values()[0], dec("19"));
+        assert!(ints.quantize(2, DecimalRounding::HalfEven).is_err());
+    }
+
+    #[test]
+    fn list_and_struct_columns_expose_offsets_through_accessors() {
+        use fp_types::{ListType, StructField};
+
+        let ints =
+            |values: &[i64]| Scalar::List(values.iter().copied().map(Scalar::Int64).collect());
+        let column = Column::from_values(vec![
+            ints(&[1, 2]),
+            ints(&[]),
+            Scalar::Null(NullKind::Null),
+            ints(&[3]),
+        ])
+        .unwrap();
+        assert_eq!(column.dtype(), DType::List(ListType::new(DType::Int64)));
+        assert!(matches!(
+            &column.data,
+            Some(ColumnData::List { offsets, .. }) if offsets == &[0, 2, 2, 2, 3]
+        ));
+
+        let list = column.list().unwrap();
+        assert_eq!(
+            list.len().values(),
+            &[
+                Scalar::Int64(2),
+                Scalar::Int64(0),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(1)
+            ]
+        );
+        assert_eq!(list.len().dtype(), DType::Int64Nullable);
+        assert_eq!(
+            list.flatten().values(),
+            &[Scalar::Int64(1), Scalar::Int64(2), Scalar::Int64(3)]
+        );
+        assert_eq!(
+            list.get(-1).unwrap_err(),
+            ColumnError::ListIndexOutOfBounds { index: -1, len: 0 }
+        );
+        let (positions, exploded) = list.explode().unwrap();
+        assert_eq!(positions, vec![0, 0, 1, 2, 3]);
+        assert_eq!(exploded.dtype(), DType::Int64);
+        assert_eq!(exploded.values()[1], Scalar::Int64(2));
+        assert!(exploded.values()[2].is_missing() && exploded.values()[3].is_missing());
+
+        let heads = column.take_positions(&[0, 2, 3]);
+        assert_eq!(
+            heads.list().unwrap().get(0).unwrap().values(),
+            &[
+                Scalar::Int64(1),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(3)
+            ]
+        );
+
+        let record = |x: i64, y: &str| {
+            Scalar::Struct(vec![
+                ("x".to_owned(), Scalar::Int64(x)),
+                ("y".to_owned(), Scalar::Utf8(y.to_owned())),
+            ])
+        };
+        let structs = Column::from_values(vec![
+            record(1, "a"),
+            Scalar::Null(NullKind::Null),
+            record(3, "c"),
+        ])
+        .unwrap();
+        let accessor = structs.r#struct().unwrap();
+        assert_eq!(
+            accessor.dtypes().to_vec(),
+            vec![
+                StructField::new("x", DType::Int64),
+                StructField::new("y", DType::Utf8)
+            ]
+        );
+        let x = accessor.field("x").unwrap();
+        assert_eq!(x.values()[0], Scalar::Int64(1));
+        assert!(x.values()[1].is_missing());
+        assert_eq!(
+            accessor.field("z").unwrap_err(),
+            ColumnError::UnknownStructField {
+                name: "z".to_owned()
+            }
+        );
+        let exploded = accessor.explode();
+        assert_eq!(exploded[1].0, "y");
+        assert_eq!(exploded[1].1.values()[2], Scalar::Utf8("c".to_owned()));
+
+        assert!(matches!(
+            structs.list().unwrap_err(),
+            ColumnError::InvalidAccessor {
+                accessor: "list",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn tz_aware_columns_localize_convert_and_compare_as_instants() {
+        use super::ComparisonOp;
+        use fp_types::{
+            TimeZone, Timedelta, Timestamp, TypeError, TzAmbiguousPolicy, TzNonexistentPolicy,
+        };
+
+        let at = |s: &str| Scalar::Datetime64(Timestamp::parse(s).unwrap().nanos);
+        let walls = Column::new(
+            DType::Datetime64,
+            vec![
+                at("2021-11-07 00:30:00"),
+                at("2021-11-07 01:30:00"),
+                at("2021-11-07 01:30:00"),
+                Scalar::Null(NullKind::NaT),
+                at("2021-11-07 03:00:00"),
+            ],
+        )
+        .unwrap();
+        let ny = TimeZone::parse("America/New_York").unwrap();
+        assert!(matches!(
+            walls.tz_localize(
+                Some(ny),
+                TzAmbiguousPolicy::Raise,
+                TzNonexistentPolicy::Raise
+            ),
+            Err(ColumnError::Type(TypeError::AmbiguousTime { .. }))
+        ));
+        let aware = walls
+            .tz_localize(
+                Some(ny),
+                TzAmbiguousPolicy::Infer,
+                TzNonexistentPolicy::Raise,
+            )
+            .unwrap();
+        assert_eq!(aware.dtype(), DType::DatetimeTz(ny));
+        assert_eq!(aware.values()[1], at("2021-11-07 05:30:00"));
+        assert_eq!(aware.values()[2], at("2021-11-07 06:30:00"));
+        assert!(aware.values()[3].is_missing());
+        assert_eq!(
+            aware
+                .tz_localize(None, TzAmbiguousPolicy::Raise, TzNonexistentPolicy::Raise)
+                .unwrap(),
+            walls
+        );
+        assert!(matches!(
+            aware.tz_localize(
+                Some(ny),
+                TzAmbiguousPolicy::Raise,
+                TzNonexistentPolicy::Raise
+            ),
+            Err(ColumnError::AlreadyTzAware)
+        ));
+        assert!(matches!(
+            walls.tz_convert(Some(ny)),
+            Err(ColumnError::TzNaive)
+        ));
+
+        // Converting keeps the instants, so the two zones compare equal.
+        let paris = TimeZone::parse("Europe/Paris").unwrap();
+        let converted = aware.tz_convert(Some(paris)).unwrap();
+        assert_eq!(converted.dtype(), DType::DatetimeTz(paris));
+        let eq = aware
+            .binary_comparison(&converted, ComparisonOp::Eq)
+            .unwrap();
+        assert_eq!(
+            eq.values()
+                .iter()
+                .map(|v| matches!(v, Scalar::Bool(true)))
+                .collect::<Vec<_>>(),
+            vec![true, true, true, false, true]
+        );
+        assert!(matches!(
+            aware.binary_comparison(&walls, ComparisonOp::Eq),
+            Err(ColumnError::TzAwareNaiveMismatch { .. })
+        ));
+        let later = aware
+            .compare_scalar(&at("2021-11-07 06:00:00"), ComparisonOp::Gt)
+            .unwrap();
+        assert_eq!(later.values()[1], Scalar::Bool(false));
+        assert_eq!(later.values()[2], Scalar::Bool(true));
+
+        // Shifting keeps the zone; the difference of two instants is naive.
+        let hour = Column::new(
+            DType::Timedelta64,
+            vec![Scalar::Timedelta64(Timedelta::NANOS_PER_HOUR); 5],
+        )
+        .unwrap();
+        let shifted = aware.binary_numeric(&hour, ArithmeticOp::Add).unwrap();
+        assert_eq!(shifted.dtype(), DType::DatetimeTz(ny));
+        let elapsed = shifted
+            .binary_numeric(&converted, ArithmeticOp::Sub)
+            .unwrap();
+        assert_eq!(elapsed.dtype(), DType::Timedelta64);
+        assert_eq!(
+            elapsed.values()[0],
+            Scalar::Timedelta64(Timedelta::NANOS_PER_HOUR)
+        );
+    }
+
+    #[test]
+    fn nullable_dtypes_survive_gathers_and_null_introducing_reindex() {
+        let ints = Column::new(
+            DType::Int64Nullable,
+            vec![
+                Scalar::Int64(7),
+                Scalar::Null(NullKind::Null),
+                Scalar::Int64(9),
+            ],
+        )
+        .unwrap();
+        let all_valid = Column::new(
+            DType::Int64Nullable,
+            vec![Scalar::Int64(1), Scalar::Int64(2)],
+        )
+        .unwrap();
+        let bools = Column::new(
+            DType::BoolNullable,
+            vec![Scalar::Bool(true), Scalar::Bool(false)],
+        )
+        .unwrap();
+
+        let reindexed = ints
+            .reindex_by_positions(&[Some(2), None, Some(1)])
+            .unwrap();
+        assert_eq!(reindexed.dtype(), DType::Int64Nullable);
+        assert_eq!(
+            reindexed.values(),
+            &[
+                Scalar::Int64(9),
+                Scalar::Null(NullKind::Null),
+                Scalar::Null(NullKind::Null)
+            ]
+        );
+        let filled = all_valid.reindex_by_positions(&[None, Some(0)]).unwrap();
+        assert_eq!(filled.dtype(), DType::Int64Nullable);
+        assert_eq!(filled.values()[1], Scalar::Int64(1));
+        assert_eq!(
+            bools
+                .reindex_by_positions(&[Some(1), None])
+                .unwrap()
+                .dtype(),
+            DType::BoolNullable
+        );
+        assert_eq!(
+            all_valid
+                .reindex_by_positions(&[None, None])
+                .unwrap()
+                .dtype(),
+            DType::Int64Nullable
+        );
+
+        assert_eq!(ints.take_positions(&[0, 2]).dtype(), DType::Int64Nullable);
+        assert_eq!(bools.take_positions(&[1, 0]).dtype(), DType::BoolNullable);
+        assert_eq!(
+            all_valid.take_contiguous_range(0, 2).dtype(),
+            DType::Int64Nullable
+        );
+        // Numpy dtypes are untouched by the nullable retag: a fill leaves
+        // the float / object promotion to the frame-level introducer.
+        let numpy = Column::from_values(vec![Scalar::Int64(1)]).unwrap();
+        assert_eq!(
+            numpy
+                .reindex_by_positions(&[None, Some(0)])
+                .unwrap()
+                .dtype(),
+            DType::Int64
+        );
+        let flags = Column::from_values(vec![Scalar::Bool(true)]).unwrap();
+        assert_eq!(
+            flags
+                .reindex_by_positions(&[Some(0), None])
+                .unwrap()
+                .dtype(),
+            DType::Bool
+        );
+    }
+
+    #[test]
+    fn nullable_dtypes_survive_concat_and_shift() {
+        let masked = Column::new(
+            DType::Int64Nullable,
+            vec![Scalar::Int64(1), Scalar::Null(NullKind::Null)],
+        )
+        .unwrap();
+        let numpy = Column::from_values(vec![Scalar::Int64(3)]).unwrap();
+        let missing = Column::new(DType::Null, vec![Scalar::Null(NullKind::NaN)]).unwrap();
+
+        let combined = numpy.concat(&masked).unwrap();
+        assert_eq!(combined.dtype(), DType::Int64Nullable);
+        assert_eq!(
+            combined.values(),
+            &[
+                Scalar::Int64(3),
+                Scalar::Int64(1),
+                Scalar::Null(NullKind::Null)
+            ]
+        );
+        let padded = masked.concat(&missing).unwrap();
+        assert_eq!(padded.dtype(), DType::Int64Nullable);
+        assert_eq!(padded.values()[2], Scalar::Null(NullKind::Null));
+        assert!(matches!(
+            masked.concat(&Column::from_values(vec![Scalar::Float64(0.5)]).unwrap()),
+            Err(ColumnError::DTypeMismatch { .. })
+        ));
+
+        let flags = Column::new(
+            DType::BoolNullable,
+            vec![Scalar::Bool(true), Scalar::Null(NullKind::Null)],
+        )
+        .unwrap();
+        let shifted = flags.shift(1, Scalar::Null(NullKind::NaN)).unwrap();
+        assert_eq!(shifted.dtype(), DType::BoolNullable);
+        assert_eq!(
+            shifted.values(),
+            &[Scalar::Null(NullKind::Null), Scalar::Bool(true)]
+        );
+        let shifted = masked.shift(-1, Scalar::Null(NullKind::NaN)).unwrap();
+        assert_eq!(shifted.dtype(), DType::Int64Nullable);
+        assert_eq!(
+            shifted.values(),
+            &[Scalar::Null(NullKind::Null), Scalar::Null(NullKind::Null)]
+        );
+    }
+
     #[test]
     fn nullable_int64_from_scalars_preserves_storage() {
         use super::ColumnData;
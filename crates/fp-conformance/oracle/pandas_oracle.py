@@ -872,6 +872,64 @@ def op_csv_read_frame(pd, payload: dict[str, Any]) -> dict[str, Any]:
     return {"expected_frame": dataframe_to_json(frame, datetime_as_typed=True)}
 
 
+def op_json_normalize(pd, payload: dict[str, Any]) -> dict[str, Any]:
+    json_input = payload.get("json_input")
+    if not isinstance(json_input, str):
+        raise OracleError("json_normalize requires json_input payload")
+    try:
+        data = json.loads(json_input)
+    except ValueError as exc:
+        raise OracleError(f"json_normalize json_input is not valid JSON: {exc}") from exc
+
+    kwargs: dict[str, Any] = {}
+    record_path = payload.get("json_record_path")
+    if record_path is not None:
+        if not isinstance(record_path, list) or not all(
+            isinstance(value, str) for value in record_path
+        ):
+            raise OracleError("json_normalize json_record_path must be a list of strings")
+        kwargs["record_path"] = record_path
+    meta = payload.get("json_meta")
+    if meta is not None:
+        if not isinstance(meta, list) or not all(
+            isinstance(path, list) and path and all(isinstance(key, str) for key in path)
+            for path in meta
+        ):
+            raise OracleError("json_normalize json_meta must be a list of string lists")
+        kwargs["meta"] = meta
+    for field, kwarg in (
+        ("json_meta_prefix", "meta_prefix"),
+        ("json_record_prefix", "record_prefix"),
+        ("json_sep", "sep"),
+        ("json_errors", "errors"),
+    ):
+        value = payload.get(field)
+        if value is not None:
+            if not isinstance(value, str):
+                raise OracleError(f"json_normalize {field} must be a string")
+            kwargs[kwarg] = value
+    max_level = payload.get("json_max_level")
+    if max_level is not None:
+        if not isinstance(max_level, int) or max_level < 0:
+            raise OracleError("json_normalize json_max_level must be a non-negative integer")
+        kwargs["max_level"] = max_level
+
+    try:
+        frame = pd.json_normalize(data, **kwargs)
+    except Exception as exc:
+        raise OracleError(f"json_normalize failed: {exc}") from exc
+
+    # FrankenPandas keeps unflattened lists/dicts as compact JSON text.
+    for name in frame.columns:
+        if frame[name].dtype == object:
+            frame[name] = frame[name].map(
+                lambda value: json.dumps(value, separators=(",", ":"))
+                if isinstance(value, (list, dict))
+                else value
+            )
+    return {"expected_frame": dataframe_to_json(frame)}
+
+
 def op_index_align_union(pd, payload: dict[str, Any]) -> dict[str, Any]:
     left = payload.get("left")
     right = payload.get("right")
@@ -7691,6 +7749,8 @@ def dispatch(pd, payload: dict[str, Any]) -> dict[str, Any]:
         return op_csv_round_trip(pd, payload)
     if op in {"csv_read_frame", "csv_read_frame_default"}:
         return op_csv_read_frame(pd, payload)
+    if op == "json_normalize":
+        return op_json_normalize(pd, payload)
     if op == "index_align_union":
         return op_index_align_union(pd, payload)
     if op == "index_has_duplicates":
@@ -104,14 +104,18 @@ use fp_index::{
     validate_alignment_plan,
 };
 use fp_io::{
-    CsvOnBadLines, CsvReadOptions, ExcelReadOptions, IoError as FpIoError, JsonOrient,
-    SqlReadOptions, read_csv_str, read_csv_with_options, read_excel_bytes, read_feather_bytes,
-    read_ipc_stream_bytes, read_json_str, read_jsonl_str, read_parquet_bytes, read_sql,
-    read_sql_query, read_sql_query_with_options, read_sql_query_with_options_and_index_col,
-    read_sql_table_with_index_col, read_sql_table_with_options_and_index_col,
-    read_sql_with_index_col, read_sql_with_options, series_from_arrow_array, series_to_arrow_array,
-    write_csv_string, write_excel_bytes, write_feather_bytes, write_ipc_stream_bytes,
-    write_json_string, write_jsonl_string, write_parquet_bytes,
+    CsvOnBadLines, CsvReadOptions, ExcelReadOptions, HdfReadOptions, IoError as FpIoError,
+    JsonNormalizeErrors, JsonNormalizeOptions, JsonOrient, SasReadOptions, SpssReadOptions,
+    SqlReadOptions, StataWriteOptions, json_normalize_str, read_csv_str, read_csv_with_options,
+    read_excel_bytes, read_feather_bytes, read_hdf_bytes, read_ipc_stream_bytes, read_json_str,
+    read_jsonl_str, read_orc_bytes, read_parquet_bytes, read_pickle_bytes, read_sas_bytes,
+    read_spss_bytes, read_sql, read_sql_query, read_sql_query_with_options,
+    read_sql_query_with_options_and_index_col, read_sql_table_with_index_col,
+    read_sql_table_with_options_and_index_col, read_sql_with_index_col, read_sql_with_options,
+    read_stata_bytes, series_from_arrow_array, series_to_arrow_array, write_csv_string,
+    write_excel_bytes, write_feather_bytes, write_ipc_stream_bytes, write_json_string,
+    write_jsonl_string, write_orc_bytes, write_parquet_bytes, write_pickle_bytes,
+    write_stata_bytes_with_options,
 };
 use fp_join::{
     JoinExecutionOptions, JoinType, JoinedSeries, MergeExecutionOptions, MergeValidateMode,
@@ -1251,6 +1255,8 @@ pub enum FixtureOperation {
         alias = "data_frame_to_json_records"
     )]
     DataFrameToJsonRecords,
+    #[serde(rename = "json_normalize")]
+    JsonNormalize,
 }
 
 impl FixtureOperation {
@@ -1618,6 +1624,7 @@ impl FixtureOperation {
             Self::DataFrameResampleSum => "dataframe_resample_sum",
             Self::DataFrameResampleMean => "dataframe_resample_mean",
             Self::DataFrameToJsonRecords => "dataframe_to_json_records",
+            Self::JsonNormalize => "json_normalize",
         }
     }
 }
@@ -2031,6 +2038,20 @@ pub struct PacketFixture {
     #[serde(default)]
     pub json_orient: Option<String>,
     #[serde(default)]
+    pub json_record_path: Option<Vec<String>>,
+    #[serde(default)]
+    pub json_meta: Option<Vec<Vec<String>>>,
+    #[serde(default)]
+    pub json_meta_prefix: Option<String>,
+    #[serde(default)]
+    pub json_record_prefix: Option<String>,
+    #[serde(default)]
+    pub json_sep: Option<String>,
+    #[serde(default)]
+    pub json_max_level: Option<usize>,
+    #[serde(default)]
+    pub json_errors: Option<String>,
+    #[serde(default)]
     pub jsonl_input: Option<String>,
     #[serde(default)]
     pub parquet_input_base64: Option<String>,
@@ -2636,6 +2657,7 @@ fn compat_contract_rows_for_operation(operation: FixtureOperation) -> &'static [
         | FixtureOperation::CsvRoundTrip
         | FixtureOperation::CsvReadFrame
         | FixtureOperation::DataFrameToJsonRecords
+        | FixtureOperation::JsonNormalize
         | FixtureOperation::JsonRoundTrip
         | FixtureOperation::JsonlRoundTrip
         | FixtureOperation::ParquetRoundTrip
@@ -3550,6 +3572,22 @@ struct OracleRequest {
     #[serde(default)]
     csv_false_values: Option<Vec<String>>,
     #[serde(default)]
+    json_input: Option<String>,
+    #[serde(default)]
+    json_record_path: Option<Vec<String>>,
+    #[serde(default)]
+    json_meta: Option<Vec<Vec<String>>>,
+    #[serde(default)]
+    json_meta_prefix: Option<String>,
+    #[serde(default)]
+    json_record_prefix: Option<String>,
+    #[serde(default)]
+    json_sep: Option<String>,
+    #[serde(default)]
+    json_max_level: Option<usize>,
+    #[serde(default)]
+    json_errors: Option<String>,
+    #[serde(default)]
     loc_labels: Option<Vec<IndexLabel>>,
     #[serde(default)]
     iloc_positions: Option<Vec<i64>>,
@@ -6181,6 +6219,47 @@ fn assert_feather_roundtrip(frame: &DataFrame) -> Result<(), FpIoError> {
     Ok(())
 }
 
+fn assert_orc_roundtrip(frame: &DataFrame) -> Result<(), FpIoError> {
+    let encoded = write_orc_bytes(frame)?;
+    let reparsed = read_orc_bytes(&encoded)?;
+    if !frame.equals(&reparsed) {
+        return Err(FpIoError::Io(std::io::Error::other(
+            "orc round-trip drifted after parse/write/reparse",
+        )));
+    }
+    Ok(())
+}
+
+/// Pickle follows pandas' numpy promotion, so a bool or int column with
+/// missing values widens on the first trip; the second must be exact.
+fn assert_pickle_roundtrip(frame: &DataFrame) -> Result<(), FpIoError> {
+    let once = read_pickle_bytes(&write_pickle_bytes(frame)?)?;
+    let twice = read_pickle_bytes(&write_pickle_bytes(&once)?)?;
+    if !once.equals(&twice) {
+        return Err(FpIoError::Io(std::io::Error::other(
+            "pickle round-trip drifted after parse/write/reparse",
+        )));
+    }
+    Ok(())
+}
+
+/// Stata stores numbers as doubles and missing strings as blanks, so the
+/// first trip may narrow a frame; the second must be exact.
+fn assert_stata_roundtrip(frame: &DataFrame) -> Result<(), FpIoError> {
+    let options = StataWriteOptions {
+        include_index: false,
+        ..StataWriteOptions::default()
+    };
+    let once = read_stata_bytes(&write_stata_bytes_with_options(frame, &options)?)?;
+    let twice = read_stata_bytes(&write_stata_bytes_with_options(&once, &options)?)?;
+    if !once.equals(&twice) {
+        return Err(FpIoError::Io(std::io::Error::other(
+            "stata round-trip drifted after parse/write/reparse",
+        )));
+    }
+    Ok(())
+}
+
 fn assert_ipc_stream_roundtrip(frame: &DataFrame) -> Result<(), FpIoError> {
     let encoded = write_ipc_stream_bytes(frame)?;
     let reparsed = read_ipc_stream_bytes(&encoded)?;
@@ -6482,8 +6561,15 @@ fn fuzz_feather_scalar_for_dtype(dtype: DType, bytes: &[u8]) -> Scalar {
 
     match dtype {
         DType::Bool | DType::BoolNullable => Scalar::Bool(payload % 2 == 1),
-        DType::Int64 | DType::Int64Nullable => Scalar::Int64(i64::from(payload % 11) - 5),
-        DType::Float64 => Scalar::Float64(match payload % 6 {
+        DType::Int8 | DType::Int16 | DType::Int32 | DType::Int64 | DType::Int64Nullable => {
+            Scalar::Int64(i64::from(payload % 11) - 5)
+        }
+        DType::UInt8 | DType::UInt16 | DType::UInt32 => Scalar::Int64(i64::from(payload % 11)),
+        DType::UInt64 => Scalar::UInt64(u64::from(payload % 11)),
+        DType::Decimal { scale, .. } => {
+            Scalar::from(fp_types::Decimal::new(i128::from(payload % 11) - 5, scale))
+        }
+        DType::Float32 | DType::Float64 => Scalar::Float64(match payload % 6 {
             0 => 0.0,
             1 => 1.0,
             2 => -1.0,
@@ -6502,13 +6588,33 @@ fn fuzz_feather_scalar_for_dtype(dtype: DType, bytes: &[u8]) -> Scalar {
         DType::Timedelta64 => {
             Scalar::Timedelta64(i64::from(payload % 100) * Timedelta::NANOS_PER_HOUR)
         }
-        DType::Datetime64 => Scalar::Datetime64(i64::from(payload % 100) * 1_000_000_000),
+        DType::Datetime64 | DType::DatetimeTz(_) => {
+            Scalar::Datetime64(i64::from(payload % 100) * 1_000_000_000)
+        }
         DType::Period => Scalar::Period(Period::new(i64::from(payload % 100), PeriodFreq::Daily)),
         DType::Interval => Scalar::Interval(fp_types::Interval {
             left: f64::from(payload % 10),
             right: f64::from(payload % 10 + 5),
             closed: fp_types::IntervalClosed::Both,
         }),
+        DType::List(list) => {
+            Scalar::List(vec![
+                fuzz_feather_scalar_for_dtype(list.inner(), &bytes[1..]);
+                usize::from(payload % 3)
+            ])
+        }
+        DType::Struct(fields) => Scalar::Struct(
+            fields
+                .fields()
+                .iter()
+                .map(|field| {
+                    (
+                        field.name.clone(),
+                        fuzz_feather_scalar_for_dtype(field.dtype, &bytes[1..]),
+                    )
+                })
+                .collect(),
+        ),
     }
 }
 
@@ -7404,6 +7510,150 @@ pub fn fuzz_ipc_stream_io_bytes(input: &[u8]) -> Result<(), FpIoError> {
     }
 }
 
+/// Structure-aware fuzz entrypoint for the `fp-io` ORC reader.
+///
+/// Inputs use the same dual-mode envelope as `fuzz_feather_io_bytes()`. Raw
+/// mode (`tag % 2 == 0`) feeds the remaining bytes directly into
+/// `read_orc_bytes()`, where parser errors are acceptable but successful
+/// parses must round-trip. Synth mode projects bytes into a tiny typed
+/// `DataFrame`, serializes it with `write_orc_bytes()`, then checks the
+/// reader against that valid payload.
+pub fn fuzz_orc_io_bytes(input: &[u8]) -> Result<(), FpIoError> {
+    let Some((&mode, payload)) = input.split_first() else {
+        return Ok(());
+    };
+
+    if mode % 2 == 0 {
+        let frame = read_orc_bytes(payload)?;
+        assert_orc_roundtrip(&frame)
+    } else {
+        let frame = fuzz_feather_frame_from_bytes(payload)?;
+        assert_orc_roundtrip(&frame)
+    }
+}
+
+const FUZZ_READ_ENCODINGS: [Option<&str>; 4] =
+    [None, Some("utf-8"), Some("latin1"), Some("cp1252")];
+
+fn assert_read_frame_invariants(frame: &DataFrame, label: &str) -> Result<(), FpIoError> {
+    assert_op_chain_invariants(frame, label)
+        .map_err(|message| FpIoError::Io(std::io::Error::other(message)))
+}
+
+/// Structure-aware fuzz entrypoint for the `fp-io` SAS reader.
+///
+/// The first byte selects read options (date conversion, blank handling, a
+/// row limit and the text encoding) and the remaining bytes go to
+/// `read_sas_bytes()`, which sniffs sas7bdat vs XPORT from the header. There
+/// is no SAS writer, so successful reads are checked for shape only: every
+/// column matches the index length and `nrows` is honoured.
+pub fn fuzz_sas_io_bytes(input: &[u8]) -> Result<(), FpIoError> {
+    let Some((&mode, payload)) = input.split_first() else {
+        return Ok(());
+    };
+
+    let nrows = (mode & 0x04 != 0).then_some(usize::from(mode >> 5));
+    let options = SasReadOptions {
+        convert_dates: mode & 0x01 != 0,
+        blank_missing: mode & 0x02 != 0,
+        nrows,
+        encoding: FUZZ_READ_ENCODINGS[usize::from(mode >> 3) % 4].map(str::to_owned),
+        ..SasReadOptions::default()
+    };
+    let frame = read_sas_bytes(payload, &options)?;
+    assert_read_frame_invariants(&frame, "sas")?;
+    if let Some(limit) = nrows
+        && frame.index().len() > limit
+    {
+        return Err(FpIoError::Io(std::io::Error::other(format!(
+            "sas: read {} rows past nrows={limit}",
+            frame.index().len()
+        ))));
+    }
+    Ok(())
+}
+
+/// Structure-aware fuzz entrypoint for the `fp-io` SPSS reader.
+///
+/// The first byte selects `convert_categoricals` and an encoding override;
+/// the remaining bytes go to `read_spss_bytes()`. There is no SPSS writer,
+/// so successful reads are checked for rectangular shape only.
+pub fn fuzz_spss_io_bytes(input: &[u8]) -> Result<(), FpIoError> {
+    let Some((&mode, payload)) = input.split_first() else {
+        return Ok(());
+    };
+
+    let options = SpssReadOptions {
+        convert_categoricals: mode & 0x01 != 0,
+        encoding: FUZZ_READ_ENCODINGS[usize::from(mode >> 1) % 4].map(str::to_owned),
+        ..SpssReadOptions::default()
+    };
+    let frame = read_spss_bytes(payload, &options)?;
+    assert_read_frame_invariants(&frame, "spss")
+}
+
+/// Structure-aware fuzz entrypoint for the `fp-io` Stata reader.
+///
+/// Inputs use the same dual-mode envelope as `fuzz_feather_io_bytes()`. Raw
+/// mode (`tag % 2 == 0`) feeds the remaining bytes directly into
+/// `read_stata_bytes()`, where parser errors are acceptable but successful
+/// parses must round-trip. Synth mode projects bytes into a tiny typed
+/// `DataFrame` and checks it through `write_stata_bytes_with_options()`.
+pub fn fuzz_stata_io_bytes(input: &[u8]) -> Result<(), FpIoError> {
+    let Some((&mode, payload)) = input.split_first() else {
+        return Ok(());
+    };
+
+    if mode % 2 == 0 {
+        let frame = read_stata_bytes(payload)?;
+        assert_stata_roundtrip(&frame)
+    } else {
+        let frame = fuzz_feather_frame_from_bytes(payload)?;
+        assert_stata_roundtrip(&frame)
+    }
+}
+
+/// Structure-aware fuzz entrypoint for the `fp-io` HDF5 reader.
+///
+/// The first byte selects an optional `start`/`stop` row window and the
+/// remaining bytes go to `read_hdf_bytes()` under the default key. Parser
+/// errors are acceptable; successful reads must be rectangular.
+pub fn fuzz_hdf5_io_bytes(input: &[u8]) -> Result<(), FpIoError> {
+    let Some((&mode, payload)) = input.split_first() else {
+        return Ok(());
+    };
+
+    let options = HdfReadOptions {
+        start: (mode & 0x01 != 0).then_some(usize::from((mode >> 2) & 0x07)),
+        stop: (mode & 0x02 != 0).then_some(usize::from(mode >> 5)),
+        ..HdfReadOptions::default()
+    };
+    let frame = read_hdf_bytes(payload, &options)?;
+    assert_read_frame_invariants(&frame, "hdf5")
+}
+
+/// Structure-aware fuzz entrypoint for the `fp-io` pickle reader.
+///
+/// Inputs use the same dual-mode envelope as `fuzz_feather_io_bytes()`. Raw
+/// mode (`tag % 2 == 0`) feeds the remaining bytes directly into
+/// `read_pickle_bytes()`, where parser errors (including the memo, stack and
+/// recursion caps) are acceptable but successful parses must round-trip.
+/// Synth mode projects bytes into a tiny typed `DataFrame` and checks it
+/// through `write_pickle_bytes()`.
+pub fn fuzz_pickle_io_bytes(input: &[u8]) -> Result<(), FpIoError> {
+    let Some((&mode, payload)) = input.split_first() else {
+        return Ok(());
+    };
+
+    if mode % 2 == 0 {
+        let frame = read_pickle_bytes(payload)?;
+        assert_pickle_roundtrip(&frame)
+    } else {
+        let frame = fuzz_feather_frame_from_bytes(payload)?;
+        assert_pickle_roundtrip(&frame)
+    }
+}
+
 fn fuzz_semantic_eq_scalar(dtype_tag: u8, value_tag: u8) -> Scalar {
     let dtype = fuzz_dtype_from_byte(dtype_tag);
     match value_tag % 8 {
@@ -9547,6 +9797,28 @@ fn run_fixture_operation(
                 ),
             }
         }
+        FixtureOperation::JsonNormalize => {
+            let actual = execute_json_normalize_fixture_operation(fixture);
+            match expected {
+                ResolvedExpected::Frame(frame) => compare_dataframe_expected(&actual?, &frame),
+                ResolvedExpected::ErrorContains(substr) => match actual {
+                    Err(message) if message.contains(&substr) => Ok(()),
+                    Err(message) => Err(format!(
+                        "expected json_normalize error containing '{substr}', got '{message}'"
+                    )),
+                    Ok(_) => Err(format!(
+                        "expected json_normalize to fail with error containing '{substr}'"
+                    )),
+                },
+                ResolvedExpected::ErrorAny => match actual {
+                    Err(_) => Ok(()),
+                    Ok(_) => Err("expected json_normalize to fail".to_owned()),
+                },
+                _ => Err(
+                    "expected_frame or expected_error is required for json_normalize".to_owned(),
+                ),
+            }
+        }
         FixtureOperation::DataFrameToJsonRecords => {
             let actual = execute_dataframe_to_json_records_fixture_operation(fixture);
             match expected {
@@ -12999,6 +13271,7 @@ fn fixture_expected(fixture: &PacketFixture) -> Result<ResolvedExpected, Harness
         | FixtureOperation::SeriesSplitDf
         | FixtureOperation::SeriesExtractDf
         | FixtureOperation::CsvReadFrame
+        | FixtureOperation::JsonNormalize
         | FixtureOperation::DataFrameRollingMean
         | FixtureOperation::DataFrameResampleSum
         | FixtureOperation::DataFrameResampleMean
@@ -13213,6 +13486,14 @@ fn capture_live_oracle_expected(
         csv_parse_date_combinations: fixture.csv_parse_date_combinations.clone(),
         csv_true_values: fixture.csv_true_values.clone(),
         csv_false_values: fixture.csv_false_values.clone(),
+        json_input: fixture.json_input.clone(),
+        json_record_path: fixture.json_record_path.clone(),
+        json_meta: fixture.json_meta.clone(),
+        json_meta_prefix: fixture.json_meta_prefix.clone(),
+        json_record_prefix: fixture.json_record_prefix.clone(),
+        json_sep: fixture.json_sep.clone(),
+        json_max_level: fixture.json_max_level,
+        json_errors: fixture.json_errors.clone(),
         loc_labels: fixture.loc_labels.clone(),
         iloc_positions: fixture.iloc_positions.clone(),
         take_indices: fixture.take_indices.clone(),
@@ -13708,6 +13989,7 @@ fn capture_live_oracle_expected(
         | FixtureOperation::SeriesSplitDf
         | FixtureOperation::SeriesExtractDf
         | FixtureOperation::CsvReadFrame
+        | FixtureOperation::JsonNormalize
         | FixtureOperation::DataFrameRollingMean
         | FixtureOperation::DataFrameResampleSum
         | FixtureOperation::DataFrameResampleMean
@@ -15021,6 +15303,33 @@ fn execute_csv_read_frame_fixture_operation(fixture: &PacketFixture) -> Result<D
     read_csv_with_options(csv_input, &options).map_err(|err| format!("csv read failed: {err}"))
 }
 
+fn execute_json_normalize_fixture_operation(fixture: &PacketFixture) -> Result<DataFrame, String> {
+    let json_input = fixture
+        .json_input
+        .as_ref()
+        .ok_or_else(|| "json_input is required for json_normalize".to_owned())?;
+    let errors = match fixture.json_errors.as_deref() {
+        None => JsonNormalizeErrors::default(),
+        Some(value) => value
+            .parse::<JsonNormalizeErrors>()
+            .map_err(|err| err.to_string())?,
+    };
+
+    let mut options = JsonNormalizeOptions {
+        record_path: fixture.json_record_path.clone().unwrap_or_default(),
+        meta: fixture.json_meta.clone().unwrap_or_default(),
+        meta_prefix: fixture.json_meta_prefix.clone(),
+        record_prefix: fixture.json_record_prefix.clone(),
+        max_level: fixture.json_max_level,
+        errors,
+        ..JsonNormalizeOptions::default()
+    };
+    if let Some(sep) = &fixture.json_sep {
+        options.sep = sep.clone();
+    }
+    json_normalize_str(json_input, &options).map_err(|err| format!("json_normalize failed: {err}"))
+}
+
 fn execute_series_to_arrow_round_trip_fixture_operation(
     fixture: &PacketFixture,
 ) -> Result<Series, String> {
@@ -17386,6 +17695,8 @@ fn encode_groupby_composite_key(values: &[Scalar]) -> Result<String, String> {
         let component = match value {
             Scalar::Bool(v) => format!("b:{v}"),
             Scalar::Int64(v) => format!("i:{v}"),
+            Scalar::UInt64(v) => format!("i:{v}"),
+            Scalar::Decimal(v) => format!("d:{}", v.normalize()),
             Scalar::Float64(v) => {
                 if v.is_nan() {
                     return Err("groupby composite key component cannot be NaN".to_owned());
@@ -17416,6 +17727,7 @@ fn encode_groupby_composite_key(values: &[Scalar]) -> Result<String, String> {
                 format!("pd:{}:{}", v.freq, v.ordinal)
             }
             Scalar::Interval(iv) => format!("iv:{iv}"),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => format!("n:{nested}"),
             Scalar::Null(_) => {
                 return Err("groupby composite key component cannot be null".to_owned());
             }
@@ -18705,6 +19017,39 @@ fn execute_and_compare_differential(
                 _ => Err("expected_frame or expected_error required for csv_read_frame".to_owned()),
             }
         }
+        FixtureOperation::JsonNormalize => {
+            let actual = execute_json_normalize_fixture_operation(fixture);
+            match expected {
+                ResolvedExpected::Frame(frame) => Ok(diff_dataframe(&actual?, &frame)),
+                ResolvedExpected::ErrorContains(substr) => Ok(match actual {
+                    Err(message) if message.contains(&substr) => Vec::new(),
+                    Err(message) => vec![make_drift_record(
+                        ComparisonCategory::Value,
+                        DriftLevel::Critical,
+                        "json_normalize.error",
+                        format!(
+                            "expected json_normalize error containing '{substr}', got '{message}'"
+                        ),
+                    )],
+                    Ok(_) => vec![make_drift_record(
+                        ComparisonCategory::Value,
+                        DriftLevel::Critical,
+                        "json_normalize.error",
+                        "expected json_normalize to fail but operation succeeded".to_owned(),
+                    )],
+                }),
+                ResolvedExpected::ErrorAny => Ok(match actual {
+                    Err(_) => Vec::new(),
+                    Ok(_) => vec![make_drift_record(
+                        ComparisonCategory::Value,
+                        DriftLevel::Critical,
+                        "json_normalize.error",
+                        "expected json_normalize to fail but operation succeeded".to_owned(),
+                    )],
+                }),
+                _ => Err("expected_frame or expected_error required for json_normalize".to_owned()),
+            }
+        }
         FixtureOperation::DataFrameToJsonRecords => {
             let actual = execute_dataframe_to_json_records_fixture_operation(fixture);
             match expected {
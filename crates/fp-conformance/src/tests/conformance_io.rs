@@ -3,8 +3,9 @@
 //! Per /testing-conformance-harnesses Pattern 1, each test compares
 //! FrankenPandas IO behavior with live upstream pandas for edge-case inputs:
 //! empty CSVs, single rows, missing-heavy values, quoting, bad-line handling,
-//! decimal/boolean parsing options, CSV write/reparse behavior, and JSON
-//! records serialization with duplicate index labels.
+//! decimal/boolean parsing options, CSV write/reparse behavior, JSON
+//! records serialization with duplicate index labels, and `json_normalize`
+//! flattening of nested records.
 
 use std::{
     io::Write,
@@ -223,10 +224,24 @@ sys.stdout.write(json.dumps({
 fn dtype_to_pandas_jsonl_name(dtype: DType) -> &'static str {
     match dtype {
         DType::Bool | DType::BoolNullable => "bool",
+        DType::Float32 => "float32",
         DType::Float64 => "float64",
+        DType::Int8 => "int8",
+        DType::Int16 => "int16",
+        DType::Int32 => "int32",
         DType::Int64 | DType::Int64Nullable => "int64",
-        DType::Utf8 | DType::Categorical | DType::Sparse | DType::Timedelta64 => "object",
-        DType::Datetime64 => "datetime64[ns]",
+        DType::UInt8 => "uint8",
+        DType::UInt16 => "uint16",
+        DType::UInt32 => "uint32",
+        DType::UInt64 => "uint64",
+        DType::Utf8
+        | DType::Categorical
+        | DType::Sparse
+        | DType::Timedelta64
+        | DType::Decimal { .. }
+        | DType::List(_)
+        | DType::Struct(_) => "object",
+        DType::Datetime64 | DType::DatetimeTz(_) => "datetime64[ns]",
         DType::Period => "period",
         DType::Interval => "interval",
         DType::Null => "float64",
@@ -238,6 +253,9 @@ fn scalar_to_jsonl_cell(value: &Scalar) -> Value {
         Scalar::Null(_) => serde_json::json!({"kind": "missing"}),
         Scalar::Bool(value) => serde_json::json!({"kind": "bool", "value": value}),
         Scalar::Int64(value) => serde_json::json!({"kind": "int64", "value": value}),
+        Scalar::UInt64(value) => serde_json::json!({"kind": "int64", "value": value}),
+        // The oracle's `decimal.Decimal` cells fall through to `str(value)`.
+        Scalar::Decimal(value) => serde_json::json!({"kind": "utf8", "value": value.to_string()}),
         Scalar::Float64(value) => {
             if value.is_finite() {
                 serde_json::json!({"kind": "float64", "value": value})
@@ -252,6 +270,11 @@ fn scalar_to_jsonl_cell(value: &Scalar) -> Value {
         Scalar::Datetime64(ns) => serde_json::json!({"kind": "datetime64", "value": ns}),
         Scalar::Period(p) => serde_json::json!({"kind": "period", "value": p.ordinal}),
         Scalar::Interval(iv) => serde_json::json!({"kind": "interval", "value": iv.to_string()}),
+        // The oracle's list / dict cells fall through to `str(value)`, which
+        // is the Python repr `Display` mirrors.
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+            serde_json::json!({"kind": "utf8", "value": nested.to_string()})
+        }
     }
 }
 
@@ -578,6 +601,49 @@ fn conformance_io_dataframe_to_json_records_nullable_numeric_duplicate_index() {
     check_io_fixture(fixture);
 }
 
+#[test]
+fn conformance_io_json_normalize_max_level_keeps_nested_objects() {
+    let fixture: PacketFixture = serde_json::from_value(serde_json::json!({
+        "packet_id": "FP-CONF-IO-016",
+        "case_id": "io_json_normalize_max_level_keeps_nested_objects",
+        "mode": "strict",
+        "operation": "json_normalize",
+        "oracle_source": "live_legacy_pandas",
+        "json_input": r#"[
+            {"id": 1, "info": {"name": "a", "geo": {"lat": 1.5}}},
+            {"id": 2, "info": {"name": "b", "geo": {"lat": 2.5}}, "extra": "x"}
+        ]"#,
+        "json_max_level": 1
+    }))
+    .expect("fixture");
+    check_io_fixture(fixture);
+}
+
+#[test]
+fn conformance_io_json_normalize_nested_record_path_with_missing_meta() {
+    let fixture: PacketFixture = serde_json::from_value(serde_json::json!({
+        "packet_id": "FP-CONF-IO-017",
+        "case_id": "io_json_normalize_nested_record_path_with_missing_meta",
+        "mode": "strict",
+        "operation": "json_normalize",
+        "oracle_source": "live_legacy_pandas",
+        "json_input": r#"{
+            "company": "acme",
+            "divisions": [
+                {"div": "east", "teams": [{"team": "a", "size": 3}, {"team": "b", "size": 4}]},
+                {"teams": [{"team": "c", "size": 5}]},
+                {"div": "north", "teams": null}
+            ]
+        }"#,
+        "json_record_path": ["divisions", "teams"],
+        "json_meta": [["company"], ["divisions", "div"]],
+        "json_meta_prefix": "meta_",
+        "json_errors": "ignore"
+    }))
+    .expect("fixture");
+    check_io_fixture(fixture);
+}
+
 #[test]
 fn conformance_io_jsonl_read_blank_lines_and_trailing_newline() {
     assert_jsonl_read_matches_pandas(JsonlEdgeCase {
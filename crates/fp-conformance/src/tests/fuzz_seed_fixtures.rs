@@ -317,6 +317,148 @@ fn fuzz_ipc_stream_io_bytes_reports_invalid_raw_bytes() {
     );
 }
 
+#[test]
+fn fuzz_orc_io_bytes_accepts_synthesized_and_raw_seed_fixtures() {
+    let synthesized =
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/orc_io/synthesized_valid_seed.bin");
+    fuzz_orc_io_bytes(synthesized).expect("synthesized ORC seed should parse");
+
+    let raw = include_bytes!("../../fixtures/adversarial/fuzz_corpus/orc_io/raw_zlib_seed.bin");
+    fuzz_orc_io_bytes(raw).expect("raw ORC seed should parse");
+}
+
+#[test]
+fn fuzz_orc_io_bytes_reports_invalid_raw_bytes() {
+    let seed =
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/orc_io/invalid_text_seed.bin");
+    let err = fuzz_orc_io_bytes(seed).expect_err("invalid ORC bytes should error");
+    assert!(
+        matches!(err, fp_io::IoError::Orc(_)),
+        "expected ORC parse error, got {err:?}"
+    );
+}
+
+#[test]
+fn fuzz_sas_io_bytes_accepts_sas7bdat_and_xport_seed_fixtures() {
+    let seeds: [&[u8]; 3] = [
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/sas_io/sas7bdat_u64_le_seed.bin"),
+        include_bytes!(
+            "../../fixtures/adversarial/fuzz_corpus/sas_io/sas7bdat_u32_be_rle_nrows_seed.bin"
+        ),
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/sas_io/xport_v5_latin1_seed.bin"),
+    ];
+    for seed in seeds {
+        fuzz_sas_io_bytes(seed).expect("SAS seed should parse");
+    }
+}
+
+#[test]
+fn fuzz_sas_io_bytes_reports_invalid_raw_bytes() {
+    let seed =
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/sas_io/invalid_text_seed.bin");
+    let err = fuzz_sas_io_bytes(seed).expect_err("invalid SAS bytes should error");
+    assert!(
+        matches!(err, fp_io::IoError::Sas(_)),
+        "expected SAS parse error, got {err:?}"
+    );
+}
+
+#[test]
+fn fuzz_spss_io_bytes_accepts_system_file_seed_fixtures() {
+    let seeds: [&[u8]; 2] = [
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/spss_io/bytecode_le_seed.bin"),
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/spss_io/raw_be_latin1_seed.bin"),
+    ];
+    for seed in seeds {
+        fuzz_spss_io_bytes(seed).expect("SPSS seed should parse");
+    }
+}
+
+#[test]
+fn fuzz_spss_io_bytes_reports_invalid_raw_bytes() {
+    let seed =
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/spss_io/invalid_text_seed.bin");
+    let err = fuzz_spss_io_bytes(seed).expect_err("invalid SPSS bytes should error");
+    assert!(
+        matches!(err, fp_io::IoError::Spss(_)),
+        "expected SPSS parse error, got {err:?}"
+    );
+}
+
+#[test]
+fn fuzz_stata_io_bytes_accepts_synthesized_and_raw_seed_fixtures() {
+    let synthesized = include_bytes!(
+        "../../fixtures/adversarial/fuzz_corpus/stata_io/synthesized_valid_seed.bin"
+    );
+    fuzz_stata_io_bytes(synthesized).expect("synthesized Stata seed should parse");
+
+    let raw = include_bytes!("../../fixtures/adversarial/fuzz_corpus/stata_io/raw_frame_seed.bin");
+    fuzz_stata_io_bytes(raw).expect("raw Stata seed should parse");
+}
+
+#[test]
+fn fuzz_stata_io_bytes_reports_invalid_raw_bytes() {
+    let seed =
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/stata_io/invalid_text_seed.bin");
+    let err = fuzz_stata_io_bytes(seed).expect_err("invalid Stata bytes should error");
+    assert!(
+        matches!(err, fp_io::IoError::Stata(_)),
+        "expected Stata parse error, got {err:?}"
+    );
+}
+
+#[test]
+fn fuzz_hdf5_io_bytes_accepts_fixed_frame_seed_fixtures() {
+    let seeds: [&[u8]; 2] = [
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/hdf5_io/fixed_frame_seed.bin"),
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/hdf5_io/fixed_frame_start_seed.bin"),
+    ];
+    for seed in seeds {
+        fuzz_hdf5_io_bytes(seed).expect("HDF5 seed should parse");
+    }
+}
+
+#[test]
+fn fuzz_hdf5_io_bytes_reports_invalid_raw_bytes() {
+    let seed =
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/hdf5_io/invalid_text_seed.bin");
+    let err = fuzz_hdf5_io_bytes(seed).expect_err("invalid HDF5 bytes should error");
+    assert!(
+        matches!(err, fp_io::IoError::Hdf5(_)),
+        "expected HDF5 parse error, got {err:?}"
+    );
+}
+
+#[test]
+fn fuzz_pickle_io_bytes_accepts_synthesized_and_raw_seed_fixtures() {
+    let synthesized = include_bytes!(
+        "../../fixtures/adversarial/fuzz_corpus/pickle_io/synthesized_valid_seed.bin"
+    );
+    fuzz_pickle_io_bytes(synthesized).expect("synthesized pickle seed should parse");
+
+    let raw = include_bytes!("../../fixtures/adversarial/fuzz_corpus/pickle_io/raw_frame_seed.bin");
+    fuzz_pickle_io_bytes(raw).expect("raw pickle seed should parse");
+}
+
+#[test]
+fn fuzz_pickle_io_bytes_reports_invalid_and_recursive_raw_bytes() {
+    let invalid =
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/pickle_io/invalid_text_seed.bin");
+    let err = fuzz_pickle_io_bytes(invalid).expect_err("invalid pickle bytes should error");
+    assert!(
+        matches!(err, fp_io::IoError::Pickle(_)),
+        "expected Pickle parse error, got {err:?}"
+    );
+
+    let recursive =
+        include_bytes!("../../fixtures/adversarial/fuzz_corpus/pickle_io/recursive_list_seed.bin");
+    let err = fuzz_pickle_io_bytes(recursive).expect_err("recursive pickle should error");
+    assert!(
+        matches!(&err, fp_io::IoError::Pickle(message) if message.contains("recursive")),
+        "expected recursive pickle error, got {err:?}"
+    );
+}
+
 #[test]
 fn fuzz_read_sql_bytes_accepts_indexed_query_dispatch_seed() {
     let mut seed = vec![0xff, 0x15];
@@ -1074,6 +1074,33 @@ fn packet_filter_runs_dataframe_to_json_records_packet() {
     assert!(report.is_green(), "expected report green: {report:?}");
 }
 
+#[test]
+fn packet_filter_runs_json_normalize_nested_objects_sep_packet() {
+    let cfg = HarnessConfig::default_paths();
+    let report = run_packet_by_id(&cfg, "FP-P2D-440", OracleMode::FixtureExpected).expect("report");
+    assert_eq!(report.packet_id.as_deref(), Some("FP-P2D-440"));
+    assert_eq!(report.fixture_count, 1);
+    assert!(report.is_green(), "expected report green: {report:?}");
+}
+
+#[test]
+fn packet_filter_runs_json_normalize_record_path_meta_packet() {
+    let cfg = HarnessConfig::default_paths();
+    let report = run_packet_by_id(&cfg, "FP-P2D-441", OracleMode::FixtureExpected).expect("report");
+    assert_eq!(report.packet_id.as_deref(), Some("FP-P2D-441"));
+    assert_eq!(report.fixture_count, 1);
+    assert!(report.is_green(), "expected report green: {report:?}");
+}
+
+#[test]
+fn packet_filter_runs_json_normalize_ignore_missing_meta_prefix_packet() {
+    let cfg = HarnessConfig::default_paths();
+    let report = run_packet_by_id(&cfg, "FP-P2D-442", OracleMode::FixtureExpected).expect("report");
+    assert_eq!(report.packet_id.as_deref(), Some("FP-P2D-442"));
+    assert_eq!(report.fixture_count, 1);
+    assert!(report.is_green(), "expected report green: {report:?}");
+}
+
 #[test]
 fn packet_filter_runs_series_timedelta_total_seconds_packet() {
     let cfg = HarnessConfig::default_paths();
@@ -1641,6 +1668,15 @@ fn packet_filter_runs_dataframe_reindex_packet() {
     assert!(report.is_green(), "expected report green: {report:?}");
 }
 
+#[test]
+fn packet_filter_runs_nullable_int_boolean_fill_packet() {
+    let cfg = HarnessConfig::default_paths();
+    let report = run_packet_by_id(&cfg, "FP-P2D-443", OracleMode::FixtureExpected).expect("report");
+    assert_eq!(report.packet_id.as_deref(), Some("FP-P2D-443"));
+    assert_eq!(report.fixture_count, 5);
+    assert!(report.is_green(), "expected report green: {report:?}");
+}
+
 #[test]
 fn packet_filter_runs_dataframe_reindex_columns_packet() {
     let cfg = HarnessConfig::default_paths();
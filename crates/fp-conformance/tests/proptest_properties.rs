@@ -17,7 +17,7 @@ use fp_join::{
     JoinExecutionOptions, JoinType, JoinedSeries, join_series, join_series_with_options,
 };
 use fp_runtime::{EvidenceLedger, RuntimePolicy};
-use fp_types::{DType, NullKind, Scalar, Timedelta};
+use fp_types::{DType, Decimal, NullKind, Scalar, Timedelta};
 use proptest::prelude::*;
 
 // ---------------------------------------------------------------------------
@@ -206,6 +206,8 @@ fn arb_combine_first_dataframe(max_rows: usize) -> impl Strategy<Value = DataFra
 fn poison_numeric_scalar(value: &Scalar) -> Scalar {
     match value {
         Scalar::Int64(v) => Scalar::Int64(v.saturating_add(1)),
+        Scalar::UInt64(v) => Scalar::UInt64(v.saturating_add(1)),
+        Scalar::Decimal(d) => Scalar::from(Decimal::new(d.value.saturating_add(1), d.scale)),
         Scalar::Float64(v) if v.is_finite() => Scalar::Float64(v + 1.0),
         Scalar::Float64(_) => Scalar::Float64(0.0),
         Scalar::Null(_) => Scalar::Int64(1),
@@ -218,6 +220,13 @@ fn poison_numeric_scalar(value: &Scalar) -> Scalar {
         Scalar::Datetime64(v) => Scalar::Datetime64(v.saturating_add(1)),
         Scalar::Period(v) => Scalar::Period(v.shift(1)),
         Scalar::Interval(iv) => Scalar::Interval(*iv),
+        Scalar::List(items) => Scalar::List(items.iter().map(poison_numeric_scalar).collect()),
+        Scalar::Struct(fields) => Scalar::Struct(
+            fields
+                .iter()
+                .map(|(name, value)| (name.clone(), poison_numeric_scalar(value)))
+                .collect(),
+        ),
     }
 }
 
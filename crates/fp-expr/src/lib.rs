@@ -2281,7 +2281,15 @@ fn parse_dtype_alias(value: &str) -> Result<DType, ExprError> {
     match value.to_ascii_lowercase().as_str() {
         "null" | "none" => Ok(DType::Null),
         "bool" | "boolean" | "?" => Ok(DType::Bool),
+        "int8" | "i1" => Ok(DType::Int8),
+        "int16" | "i2" => Ok(DType::Int16),
+        "int32" | "i4" => Ok(DType::Int32),
         "int" | "integer" | "int64" | "i8" => Ok(DType::Int64),
+        "uint8" | "u1" => Ok(DType::UInt8),
+        "uint16" | "u2" => Ok(DType::UInt16),
+        "uint32" | "u4" => Ok(DType::UInt32),
+        "uint64" | "u8" => Ok(DType::UInt64),
+        "float32" | "f4" => Ok(DType::Float32),
         "float" | "floating" | "float64" | "f8" => Ok(DType::Float64),
         "object" | "string" | "str" | "utf8" | "o" => Ok(DType::Utf8),
         "category" | "categorical" => Ok(DType::Categorical),
@@ -2290,12 +2298,30 @@ fn parse_dtype_alias(value: &str) -> Result<DType, ExprError> {
         "period" => Ok(DType::Period),
         "interval" => Ok(DType::Interval),
         "sparse" => Ok(DType::Sparse),
-        other => Err(ExprError::ParseError(format!(
-            "astype() dtype is not supported in expressions: {other:?}"
-        ))),
+        other => parse_decimal_dtype_alias(other).ok_or_else(|| {
+            ExprError::ParseError(format!(
+                "astype() dtype is not supported in expressions: {other:?}"
+            ))
+        }),
     }
 }
 
+/// pyarrow-style `decimal128(precision, scale)` (or `decimal(...)`); the
+/// scale defaults to 0 and may not exceed the precision.
+fn parse_decimal_dtype_alias(value: &str) -> Option<DType> {
+    let args = value
+        .strip_prefix("decimal128")
+        .or_else(|| value.strip_prefix("decimal"))?
+        .trim()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    let (precision, scale) = args.split_once(',').unwrap_or((args, "0"));
+    let precision = precision.trim().parse::<u8>().ok()?;
+    let scale = scale.trim().parse::<u8>().ok()?;
+    ((1..=fp_types::DECIMAL128_MAX_PRECISION).contains(&precision) && scale <= precision)
+        .then_some(DType::Decimal { precision, scale })
+}
+
 fn parse_dtype_literal(tokens: &[Token], pos: &mut usize) -> Result<DType, ExprError> {
     let dtype = parse_scalar_literal(tokens, pos)?;
     let Scalar::Utf8(value) = dtype else {
@@ -69,7 +69,10 @@ use fp_columnar::{Column, ColumnError};
 use fp_frame::{FrameError, Series};
 use fp_index::{Index, IndexError, IndexLabel, align_union, validate_alignment_plan};
 use fp_runtime::{EvidenceLedger, RuntimePolicy};
-use fp_types::{DType, IntervalClosed, NullKind, PeriodFreq, Scalar, Timedelta, Timestamp};
+use fp_types::{
+    DECIMAL128_MAX_PRECISION, DType, Decimal, IntervalClosed, NullKind, PeriodFreq, Scalar,
+    Timedelta, Timestamp,
+};
 // Group accumulation maps key on GroupKeyRef and read group ORDER from a
 // separate `ordering` Vec (first-seen order), never from map iteration. So the
 // hasher is observationally invisible: swapping SipHash -> FxHash changes only
@@ -185,7 +188,10 @@ fn groupby_sum_with_trace(
     // the current groupby output behavior.
     let _ = policy.decide_join_admission(input_rows, ledger);
     let estimated_bytes = estimate_groupby_intermediate_bytes(input_rows);
-    let use_arena = exec_options.use_arena && estimated_bytes <= exec_options.arena_budget_bytes;
+    let nullable_sum = nullable_agg_dtype(values.column().dtype(), AggFunc::Sum);
+    let use_arena = exec_options.use_arena
+        && estimated_bytes <= exec_options.arena_budget_bytes
+        && nullable_sum.is_none();
 
     // Identity-aligned, all-valid Int64 columns already expose contiguous raw
     // buffers. Keep the dense direct-address algorithm, but run it before
@@ -200,7 +206,10 @@ fn groupby_sum_with_trace(
             try_groupby_sum_dense_int64_slices(raw_keys, raw_values, options.sort)
     {
         let out_column = Column::from_values(out_values)?;
-        let result = Series::new("sum", Index::new(out_index), out_column)?;
+        let result = retag_nullable_agg(
+            Series::new("sum", Index::new(out_index), out_column)?,
+            nullable_sum,
+        )?;
         return Ok((
             result,
             GroupByExecutionTrace {
@@ -218,7 +227,17 @@ fn groupby_sum_with_trace(
             (keys.values(), values.values())
         };
 
-    let result = if use_arena {
+    let result = if let DType::Decimal { scale, .. } = values.column().dtype() {
+        groupby_sum_decimal(aligned_keys_values, aligned_values_values, scale, options)?
+    } else if nullable_sum.is_some() {
+        // Masked Int64/boolean columns skip missing values and stay integer
+        // (pd.NA never forces the Float64 promotion numpy int columns get),
+        // so they always take the i128 accumulator regardless of gaps.
+        retag_nullable_agg(
+            groupby_sum_int64(aligned_keys_values, aligned_values_values, options)?,
+            nullable_sum,
+        )?
+    } else if use_arena {
         groupby_sum_with_arena(aligned_keys_values, aligned_values_values, options)?
     } else {
         groupby_sum_with_global_allocator(aligned_keys_values, aligned_values_values, options)?
@@ -419,6 +438,10 @@ fn emit_groupby_result<'a>(
         let label = &source_keys[source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             // Typed null group label (br-frankenpandas-8m6ay): pandas
@@ -446,6 +469,8 @@ fn emit_groupby_result<'a>(
 enum GroupKeyRef<'a> {
     Bool(bool),
     Int64(i64),
+    UInt64(u64),
+    Decimal(i128, u8),
     FloatBits(u64),
     Utf8(&'a str),
     Null(NullKind),
@@ -453,6 +478,7 @@ enum GroupKeyRef<'a> {
     Datetime64(i64),
     Period(i64),
     Interval(u64, u64, fp_types::IntervalClosed),
+    Nested(String),
 }
 
 impl<'a> GroupKeyRef<'a> {
@@ -460,6 +486,16 @@ impl<'a> GroupKeyRef<'a> {
         match key {
             Scalar::Bool(v) => Self::Bool(*v),
             Scalar::Int64(v) => Self::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v).map_or(Self::UInt64(*v), Self::Int64),
+            // Trailing zeros are dropped so `1.50` and `1.5` share a group,
+            // and integral decimals join the matching integer key.
+            Scalar::Decimal(v) => {
+                let v = v.normalize();
+                match i64::try_from(v.value) {
+                    Ok(int) if v.scale == 0 => Self::Int64(int),
+                    _ => Self::Decimal(v.value, v.scale),
+                }
+            }
             Scalar::Float64(v) => {
                 if v.is_nan() {
                     Self::FloatBits(f64::NAN.to_bits())
@@ -494,6 +530,7 @@ impl<'a> GroupKeyRef<'a> {
             Scalar::Interval(iv) => {
                 Self::Interval(iv.left.to_bits(), iv.right.to_bits(), iv.closed)
             }
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => Self::Nested(nested.to_string()),
         }
     }
 }
@@ -588,6 +625,10 @@ fn groupby_sum_timedelta64(
         let label = &keys[source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             // Typed null group label (br-frankenpandas-8m6ay): pandas
@@ -672,6 +713,10 @@ fn groupby_sum_utf8(
         let label = &keys[source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             // Typed null group label (br-frankenpandas-8m6ay): pandas
@@ -695,6 +740,66 @@ fn groupby_sum_utf8(
     Ok(Series::new("sum", Index::new(out_index), out_column)?)
 }
 
+/// Exact groupby sum for `Decimal` columns. Each group folds its non-missing
+/// values at the column scale and emits `Decimal(38, scale)`, like pyarrow's
+/// `hash_sum` on decimal128; an empty / all-null group sums to zero. A group
+/// total past 38 digits raises `DecimalOverflow` instead of going to Float64.
+fn groupby_sum_decimal(
+    keys: &[Scalar],
+    values: &[Scalar],
+    scale: u8,
+    options: GroupByOptions,
+) -> Result<Series, GroupByError> {
+    let mut ordering = Vec::<GroupKeyRef<'_>>::new();
+    let mut slot = FxHashMap::<GroupKeyRef<'_>, (usize, Decimal)>::default();
+
+    for (pos, (key, value)) in keys.iter().zip(values.iter()).enumerate() {
+        if options.dropna && key.is_missing() {
+            continue;
+        }
+        let key_id = GroupKeyRef::from_scalar(key);
+        let entry = slot.entry(key_id.clone()).or_insert_with(|| {
+            ordering.push(key_id.clone());
+            (pos, Decimal::new(0, scale))
+        });
+        if let Scalar::Decimal(v) = value {
+            entry.1 = entry
+                .1
+                .checked_add(**v)
+                .map_err(ColumnError::from)?
+                .check_precision(DECIMAL128_MAX_PRECISION)
+                .map_err(ColumnError::from)?;
+        }
+    }
+
+    if options.sort {
+        sort_group_ordering_by(keys, &mut ordering, |key| {
+            slot.get(key)
+                .expect("ordering references only inserted keys")
+                .0
+        });
+    }
+
+    let mut out_index = Vec::with_capacity(ordering.len());
+    let mut out_values = Vec::with_capacity(ordering.len());
+    for key in &ordering {
+        let (source_idx, sum) = slot
+            .remove(key)
+            .expect("ordering references only inserted keys");
+        out_index.push(scalar_group_label(&keys[source_idx]));
+        out_values.push(Scalar::from(sum));
+    }
+
+    let out_column = Column::new(
+        DType::Decimal {
+            precision: DECIMAL128_MAX_PRECISION,
+            scale,
+        },
+        out_values,
+    )?;
+    Ok(Series::new("sum", Index::new(out_index), out_column)?)
+}
+
 /// Detect uniformly-Int64-or-Bool value input (allowing Null missing markers).
 /// Mirrors `is_timedelta_values`/`is_utf8_values`. A pandas integer/boolean
 /// column sums dtype-preservingly to Int64, so route it to `groupby_sum_int64`
@@ -770,6 +875,10 @@ fn groupby_sum_int64(
         let label = &keys[source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             // Typed null group label (br-frankenpandas-8m6ay): pandas
@@ -1721,6 +1830,10 @@ fn try_groupby_count_size_counter(
         let label = &keys[*source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             Scalar::Null(NullKind::NaT) => IndexLabel::Null(NullKind::NaT),
@@ -1796,6 +1909,10 @@ fn try_groupby_mean_numeric_counter(
         let label = &keys[*source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             Scalar::Null(NullKind::NaT) => IndexLabel::Null(NullKind::NaT),
@@ -1911,6 +2028,10 @@ fn try_groupby_var_std_numeric_counter(
         let label = &keys[group.source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             Scalar::Null(NullKind::NaT) => IndexLabel::Null(NullKind::NaT),
@@ -1939,6 +2060,11 @@ fn try_groupby_var_std_numeric_counter(
 fn scalar_group_label(label: &Scalar) -> IndexLabel {
     match label {
         Scalar::Int64(v) => IndexLabel::Int64(*v),
+        Scalar::UInt64(v) => {
+            i64::try_from(*v).map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64)
+        }
+        Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
         Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
         Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
         Scalar::Null(NullKind::NaT) => IndexLabel::Null(NullKind::NaT),
@@ -2042,12 +2168,15 @@ fn try_groupby_median_numeric_vectors(
 enum NuniqueValueKey<'a> {
     Bool(bool),
     Int64(i64),
+    UInt64(u64),
+    Decimal(i128, u8),
     FloatBits(u64),
     Utf8(&'a str),
     Timedelta64(i64),
     Datetime64(i64),
     Period(i64, PeriodFreq),
     Interval(u64, u64, IntervalClosed),
+    Nested(String),
 }
 
 fn nunique_value_key(value: &Scalar) -> Option<NuniqueValueKey<'_>> {
@@ -2057,6 +2186,16 @@ fn nunique_value_key(value: &Scalar) -> Option<NuniqueValueKey<'_>> {
     Some(match value {
         Scalar::Bool(v) => NuniqueValueKey::Bool(*v),
         Scalar::Int64(v) => NuniqueValueKey::Int64(*v),
+        Scalar::UInt64(v) => {
+            i64::try_from(*v).map_or(NuniqueValueKey::UInt64(*v), NuniqueValueKey::Int64)
+        }
+        Scalar::Decimal(v) => {
+            let v = v.normalize();
+            match i64::try_from(v.value) {
+                Ok(int) if v.scale == 0 => NuniqueValueKey::Int64(int),
+                _ => NuniqueValueKey::Decimal(v.value, v.scale),
+            }
+        }
         Scalar::Float64(v) => {
             let normalized = if *v == 0.0 { 0.0 } else { *v };
             NuniqueValueKey::FloatBits(normalized.to_bits())
@@ -2070,6 +2209,9 @@ fn nunique_value_key(value: &Scalar) -> Option<NuniqueValueKey<'_>> {
             if v.right == 0.0 { 0.0 } else { v.right }.to_bits(),
             v.closed,
         ),
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+            NuniqueValueKey::Nested(nested.to_string())
+        }
         Scalar::Null(_) => return None,
     })
 }
@@ -2261,6 +2403,10 @@ fn try_groupby_min_max_scalar_slot(
         let label = &keys[*source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             Scalar::Null(NullKind::NaT) => IndexLabel::Null(NullKind::NaT),
@@ -2339,6 +2485,10 @@ fn try_groupby_first_last_scalar_slot(
         let label = &keys[*source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             Scalar::Null(NullKind::NaT) => IndexLabel::Null(NullKind::NaT),
@@ -2386,7 +2536,7 @@ fn try_groupby_sum_prod_integer_counter(
     sort: bool,
 ) -> Option<(Vec<IndexLabel>, Vec<Scalar>)> {
     if !matches!(func, AggFunc::Sum | AggFunc::Prod)
-        || !matches!(value_dtype, DType::Int64 | DType::Bool)
+        || !matches!(value_dtype.to_non_nullable(), DType::Int64 | DType::Bool)
     {
         return None;
     }
@@ -2444,6 +2594,10 @@ fn try_groupby_sum_prod_integer_counter(
         let label = &keys[group.source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             Scalar::Null(NullKind::NaT) => IndexLabel::Null(NullKind::NaT),
@@ -2538,6 +2692,10 @@ fn try_groupby_sum_prod_float_counter(
         let label = &keys[group.source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             Scalar::Null(NullKind::NaT) => IndexLabel::Null(NullKind::NaT),
@@ -2558,6 +2716,63 @@ fn try_groupby_sum_prod_float_counter(
     Some((out_index, out_values))
 }
 
+/// Output dtype for a reduction over a nullable extension column.
+///
+/// pandas keeps `Int64`/`boolean` inputs on the masked path: sum/prod of
+/// either yield `Int64`, and min/max/first/last keep the input dtype, with
+/// empty groups surfacing as `pd.NA`. Every other reduction (mean, var,
+/// count, ...) already has a numpy-typed result and returns `None`.
+fn nullable_agg_dtype(value_dtype: DType, func: AggFunc) -> Option<DType> {
+    match (value_dtype, func) {
+        (DType::Int64Nullable | DType::BoolNullable, AggFunc::Sum | AggFunc::Prod) => {
+            Some(DType::Int64Nullable)
+        }
+        (
+            DType::Int64Nullable | DType::BoolNullable,
+            AggFunc::Min | AggFunc::Max | AggFunc::First | AggFunc::Last,
+        ) => Some(value_dtype),
+        _ => None,
+    }
+}
+
+/// Re-tag a reduction result with the nullable dtype chosen by
+/// [`nullable_agg_dtype`]. The kernels emit numpy-typed scalars (and `NaN`
+/// for empty groups); when every value fits the nullable dtype's base type
+/// the column is rebuilt with `pd.NA` missing markers. A result that left
+/// the integer domain (an i64-overflowing sum promoted to Float64) is kept
+/// as-is.
+fn retag_nullable_agg(result: Series, target: Option<DType>) -> Result<Series, GroupByError> {
+    let Some(target) = target else {
+        return Ok(result);
+    };
+    let base = target.to_non_nullable();
+    let fits = result.values().iter().all(|value| match value {
+        Scalar::Int64(_) => base == DType::Int64,
+        Scalar::Bool(_) => base == DType::Bool,
+        other => other.is_missing(),
+    });
+    if !fits {
+        return Ok(result);
+    }
+    let values = result
+        .values()
+        .iter()
+        .map(|value| {
+            if value.is_missing() {
+                Scalar::Null(NullKind::Null)
+            } else {
+                value.clone()
+            }
+        })
+        .collect();
+    let column = Column::new(target, values)?;
+    Ok(Series::new(
+        result.name().to_owned(),
+        result.index().clone(),
+        column,
+    )?)
+}
+
 pub fn groupby_agg(
     keys: &Series,
     values: &Series,
@@ -2565,6 +2780,18 @@ pub fn groupby_agg(
     options: GroupByOptions,
     policy: &RuntimePolicy,
     ledger: &mut EvidenceLedger,
+) -> Result<Series, GroupByError> {
+    let result = groupby_agg_untagged(keys, values, func, options, policy, ledger)?;
+    retag_nullable_agg(result, nullable_agg_dtype(values.column().dtype(), func))
+}
+
+fn groupby_agg_untagged(
+    keys: &Series,
+    values: &Series,
+    func: AggFunc,
+    options: GroupByOptions,
+    policy: &RuntimePolicy,
+    ledger: &mut EvidenceLedger,
 ) -> Result<Series, GroupByError> {
     // Alignment: if indexes differ, align to union.
     let aligned_storage = if keys.index() == values.index() && !keys.index().has_duplicates() {
@@ -2815,6 +3042,10 @@ pub fn groupby_agg(
         let label = &key_vals[*source_idx];
         out_index.push(match label {
             Scalar::Int64(v) => IndexLabel::Int64(*v),
+            Scalar::UInt64(v) => i64::try_from(*v)
+                .map_or_else(|_| IndexLabel::Utf8(v.to_string()), IndexLabel::Int64),
+            Scalar::Decimal(v) => IndexLabel::Utf8(v.to_string()),
+            nested @ (Scalar::List(_) | Scalar::Struct(_)) => IndexLabel::Utf8(nested.to_string()),
             Scalar::Utf8(v) => IndexLabel::Utf8(v.clone()),
             Scalar::Bool(v) => IndexLabel::Utf8(if *v { "True" } else { "False" }.to_string()),
             // Typed null group label (br-frankenpandas-8m6ay): collapsed nan
@@ -2829,7 +3060,7 @@ pub fn groupby_agg(
         });
 
         let agg_value = match func {
-            AggFunc::Sum if matches!(value_dtype, DType::Int64 | DType::Bool) => {
+            AggFunc::Sum if matches!(value_dtype.to_non_nullable(), DType::Int64 | DType::Bool) => {
                 let mut total = 0_i128;
                 for v in vals {
                     match v {
@@ -2882,7 +3113,9 @@ pub fn groupby_agg(
             // mirroring Sum (the earlier Float64-only path diverged from pandas).
             // Accumulate an i128 product and keep Int64 when it fits; fall back to
             // the Float64 nanprod only on i64 overflow. br-frankenpandas-rl25i.
-            AggFunc::Prod if matches!(value_dtype, DType::Int64 | DType::Bool) => {
+            AggFunc::Prod
+                if matches!(value_dtype.to_non_nullable(), DType::Int64 | DType::Bool) =>
+            {
                 let mut total: Option<i128> = Some(1);
                 for v in vals {
                     let x = match v {
@@ -3056,6 +3289,11 @@ fn sketch_hash(value: u64, seed: u64) -> u64 {
 fn scalar_to_hash_bits(value: &Scalar) -> u64 {
     match value {
         Scalar::Int64(v) => *v as u64,
+        Scalar::UInt64(v) => *v,
+        Scalar::Decimal(v) => {
+            let v = v.normalize();
+            (v.value as u64) ^ ((v.value >> 64) as u64).rotate_left(u32::from(v.scale))
+        }
         Scalar::Float64(v) => {
             if v.is_nan() {
                 return 0xDEAD_BEEF_CAFE_BABE;
@@ -3076,6 +3314,9 @@ fn scalar_to_hash_bits(value: &Scalar) -> u64 {
         Scalar::Datetime64(v) => *v as u64,
         Scalar::Period(v) => v.ordinal as u64,
         Scalar::Interval(iv) => iv.left.to_bits() ^ iv.right.to_bits(),
+        nested @ (Scalar::List(_) | Scalar::Struct(_)) => {
+            scalar_to_hash_bits(&Scalar::Utf8(nested.to_string()))
+        }
     }
 }
 
@@ -3453,7 +3694,7 @@ mod tests {
     use fp_frame::Series;
     use fp_index::{Index, IndexLabel};
     use fp_runtime::{EvidenceLedger, RuntimePolicy};
-    use fp_types::{NullKind, Scalar};
+    use fp_types::{DType, NullKind, Scalar};
 
     use super::{
         GroupByExecutionOptions, GroupByOptions, groupby_nunique, groupby_prod, groupby_size,
@@ -4620,6 +4861,62 @@ mod tests {
         assert_eq!(out.values(), &[Scalar::Int64(4), Scalar::Int64(6)]);
     }
 
+    #[test]
+    fn groupby_sum_decimal_values_stay_exact() {
+        let dec = |s: &str| Scalar::from(s.parse::<fp_types::Decimal>().expect("decimal"));
+        let dtype = DType::Decimal {
+            precision: 10,
+            scale: 2,
+        };
+        let keys = Series::from_values(
+            "key",
+            vec![0_i64.into(), 1_i64.into(), 2_i64.into(), 3_i64.into()],
+            vec![
+                Scalar::Utf8("b".to_owned()),
+                Scalar::Utf8("a".to_owned()),
+                Scalar::Utf8("b".to_owned()),
+                Scalar::Utf8("a".to_owned()),
+            ],
+        )
+        .expect("keys");
+        let values = Series::new(
+            "value",
+            Index::new(vec![0_i64.into(), 1_i64.into(), 2_i64.into(), 3_i64.into()]),
+            Column::new(
+                dtype,
+                vec![
+                    dec("0.10"),
+                    dec("19.99"),
+                    dec("0.20"),
+                    Scalar::Null(NullKind::Null),
+                ],
+            )
+            .expect("column"),
+        )
+        .expect("values");
+
+        let mut ledger = EvidenceLedger::new();
+        let out = groupby_sum(
+            &keys,
+            &values,
+            GroupByOptions::default(),
+            &RuntimePolicy::strict(),
+            &mut ledger,
+        )
+        .expect("groupby");
+
+        assert_eq!(out.index().labels(), &["a".into(), "b".into()]);
+        // 0.1 + 0.2 is exactly 0.30, not 0.30000000000000004.
+        assert_eq!(out.values(), &[dec("19.99"), dec("0.30")]);
+        assert_eq!(
+            out.column().dtype(),
+            DType::Decimal {
+                precision: 38,
+                scale: 2
+            }
+        );
+    }
+
     #[test]
     fn groupby_sum_records_runtime_admission_evidence() {
         let keys = Series::from_values(
@@ -5654,6 +5951,114 @@ mod tests {
         assert_eq!(out.name(), "mean");
     }
 
+    #[test]
+    fn groupby_nullable_int_and_boolean_reductions_keep_masked_dtypes() {
+        use fp_types::DType;
+
+        let index = Index::from_range(0, 5, 1);
+        let keys = Series::new(
+            "key",
+            index.clone(),
+            Column::from_values(
+                ["a", "b", "a", "c", "b"]
+                    .into_iter()
+                    .map(|k| Scalar::Utf8(k.into()))
+                    .collect(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let null = Scalar::Null(NullKind::Null);
+        let ids = Series::new(
+            "id",
+            index.clone(),
+            Column::new(
+                DType::Int64Nullable,
+                vec![
+                    Scalar::Int64(4),
+                    null.clone(),
+                    Scalar::Int64(6),
+                    null.clone(),
+                    Scalar::Int64(2),
+                ],
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let flags = Series::new(
+            "flag",
+            index,
+            Column::new(
+                DType::BoolNullable,
+                vec![
+                    Scalar::Bool(true),
+                    Scalar::Bool(true),
+                    null.clone(),
+                    null.clone(),
+                    Scalar::Bool(false),
+                ],
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let mut ledger = EvidenceLedger::new();
+        let agg = |values: &Series, func: AggFunc, ledger: &mut EvidenceLedger| {
+            groupby_agg(
+                &keys,
+                values,
+                func,
+                GroupByOptions::default(),
+                &RuntimePolicy::strict(),
+                ledger,
+            )
+            .unwrap()
+        };
+
+        let sum = groupby_sum(
+            &keys,
+            &ids,
+            GroupByOptions::default(),
+            &RuntimePolicy::strict(),
+            &mut ledger,
+        )
+        .unwrap();
+        assert_eq!(sum.column().dtype(), DType::Int64Nullable);
+        assert_eq!(
+            sum.values(),
+            &[Scalar::Int64(10), Scalar::Int64(2), Scalar::Int64(0)]
+        );
+        let agg_sum = agg(&ids, AggFunc::Sum, &mut ledger);
+        assert_eq!(agg_sum.column().dtype(), DType::Int64Nullable);
+        assert_eq!(agg_sum.values(), sum.values());
+
+        let min = agg(&ids, AggFunc::Min, &mut ledger);
+        assert_eq!(min.column().dtype(), DType::Int64Nullable);
+        assert_eq!(
+            min.values(),
+            &[Scalar::Int64(4), Scalar::Int64(2), null.clone()]
+        );
+        let first = agg(&ids, AggFunc::First, &mut ledger);
+        assert_eq!(first.column().dtype(), DType::Int64Nullable);
+        assert_eq!(first.values()[2], null);
+
+        let flag_sum = agg(&flags, AggFunc::Sum, &mut ledger);
+        assert_eq!(flag_sum.column().dtype(), DType::Int64Nullable);
+        assert_eq!(
+            flag_sum.values(),
+            &[Scalar::Int64(1), Scalar::Int64(1), Scalar::Int64(0)]
+        );
+        let flag_max = agg(&flags, AggFunc::Max, &mut ledger);
+        assert_eq!(flag_max.column().dtype(), DType::BoolNullable);
+        assert_eq!(
+            flag_max.values(),
+            &[Scalar::Bool(true), Scalar::Bool(true), null]
+        );
+
+        // Reductions with numpy-typed results are unaffected.
+        let mean = agg(&ids, AggFunc::Mean, &mut ledger);
+        assert_eq!(mean.column().dtype(), DType::Float64);
+    }
+
     #[test]
     fn groupby_count_basic() {
         let (keys, values) = make_grouped_data();
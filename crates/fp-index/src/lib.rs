@@ -84,7 +84,10 @@ use std::{
 };
 
 use chrono::Datelike;
-use fp_types::{Period, PeriodFreq, Scalar, Timedelta, TimedeltaComponents};
+use fp_types::{
+    Period, PeriodFreq, Scalar, TimeZone, Timedelta, TimedeltaComponents, TzAmbiguousPolicy,
+    TzNonexistentPolicy,
+};
 // Dedup / set-op seen-sets key on &IndexLabel and read output order from the
 // INPUT scan (first-seen filter / positional bool), never from map iteration —
 // so the hasher is observationally invisible. FxHash (rustc-hash, pure safe
@@ -5983,6 +5986,10 @@ fn datetime_nanos_to_period(nanos: i64, freq: PeriodFreq) -> Result<Period, Inde
     datetime_period_ordinal(nanos, freq).map(|ordinal| Period::new(ordinal, freq))
 }
 
+fn parse_time_zone(name: &str) -> Result<TimeZone, IndexError> {
+    TimeZone::parse(name).map_err(|err| IndexError::InvalidArgument(err.to_string()))
+}
+
 fn map_datetime_labels<T, F>(labels: &[IndexLabel], func: F) -> Vec<Option<T>>
 where
     F: Fn(chrono::DateTime<chrono::Utc>) -> T,
@@ -6535,9 +6542,15 @@ fn ensure_index_kind(
 /// DataFrame/Series alignment code keeps one representation. This wrapper adds
 /// the type-level public surface pandas users expect (`DatetimeIndex`) and a
 /// small first slice of datetime accessors.
+///
+/// Labels are UTC instants. An optional [`TimeZone`] makes the index
+/// tz-aware: field accessors, `strftime` and day-based rounding then read
+/// the local wall clock.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DatetimeIndex {
     index: Index,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tz: Option<TimeZone>,
 }
 
 impl DatetimeIndex {
@@ -6545,16 +6558,22 @@ impl DatetimeIndex {
     pub fn new(nanos: Vec<i64>) -> Self {
         Self {
             index: Index::from_datetime64(nanos),
+            tz: None,
         }
     }
 
+    fn with_time_zone(mut self, tz: Option<TimeZone>) -> Self {
+        self.tz = tz;
+        self
+    }
+
     pub fn from_index(index: Index) -> Result<Self, IndexError> {
         ensure_index_kind(
             &index,
             |label| matches!(label, IndexLabel::Datetime64(_)),
             "DatetimeIndex",
         )?;
-        Ok(Self { index })
+        Ok(Self { index, tz: None })
     }
 
     #[must_use]
@@ -6586,6 +6605,7 @@ impl DatetimeIndex {
     pub fn set_name(&self, name: &str) -> Self {
         Self {
             index: self.index.set_name(name),
+            tz: self.tz,
         }
     }
 
@@ -6593,6 +6613,7 @@ impl DatetimeIndex {
     pub fn set_names(&self, name: Option<&str>) -> Self {
         Self {
             index: self.index.set_names(name),
+            tz: self.tz,
         }
     }
 
@@ -6862,7 +6883,7 @@ impl DatetimeIndex {
     /// `pd.DatetimeIndex.strftime(format)`. NAT propagates as `None`.
     #[must_use]
     pub fn strftime(&self, format: &str) -> Vec<Option<String>> {
-        map_datetime_labels(self.index.labels(), |dt| dt.format(format).to_string())
+        self.map_wall_clock(|dt| dt.format(format).to_string())
     }
 
     /// Position of the maximum label, matching `pd.DatetimeIndex.argmax()`.
@@ -6924,14 +6945,14 @@ impl DatetimeIndex {
     /// First-seen unique labels, matching `pd.DatetimeIndex.unique()`.
     /// Returns a new DatetimeIndex.
     pub fn unique(&self) -> Result<Self, IndexError> {
-        Self::from_index(self.index.unique())
+        Ok(Self::from_index(self.index.unique())?.with_time_zone(self.tz))
     }
 
     /// Identity-stable factorization, matching `pd.DatetimeIndex.factorize()`.
     /// Returns `(codes, uniques)` where `uniques` is rebuilt as DatetimeIndex.
     pub fn factorize(&self) -> Result<(Vec<isize>, Self), IndexError> {
         let (codes, uniques) = self.index.factorize();
-        Ok((codes, Self::from_index(uniques)?))
+        Ok((codes, Self::from_index(uniques)?.with_time_zone(self.tz)))
     }
 
     /// Value counts, matching `pd.DatetimeIndex.value_counts()`.
@@ -6948,7 +6969,7 @@ impl DatetimeIndex {
 
     /// Drop duplicate labels, matching `pd.DatetimeIndex.drop_duplicates()`.
     pub fn drop_duplicates(&self) -> Result<Self, IndexError> {
-        Self::from_index(self.index.drop_duplicates())
+        Ok(Self::from_index(self.index.drop_duplicates())?.with_time_zone(self.tz))
     }
 
     /// Pick labels at the given positions, matching `pd.DatetimeIndex.take()`.
@@ -6970,7 +6991,7 @@ impl DatetimeIndex {
                 _ => i64::MIN,
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
@@ -6988,7 +7009,7 @@ impl DatetimeIndex {
                 }
             }
         }
-        let mut result = Self::new(out);
+        let mut result = Self::new(out).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             result = result.set_name(name);
         }
@@ -7013,7 +7034,8 @@ impl DatetimeIndex {
 
     /// Concatenate with another DatetimeIndex, matching
     /// `pd.DatetimeIndex.append(other)`. The index name is preserved when
-    /// both operands share it; otherwise pandas drops the name.
+    /// both operands share it; otherwise pandas drops the name. Two
+    /// different zones meet in UTC.
     #[must_use]
     pub fn append(&self, other: &Self) -> Self {
         let mut nanos: Vec<i64> = self
@@ -7029,7 +7051,11 @@ impl DatetimeIndex {
             IndexLabel::Datetime64(n) => Some(*n),
             _ => None,
         }));
-        let mut out = Self::new(nanos);
+        let tz = match (self.tz, other.tz) {
+            (Some(left), Some(right)) if left != right => Some(TimeZone::UTC),
+            (left, _) => left,
+        };
+        let mut out = Self::new(nanos).with_time_zone(tz);
         if let Some(name) = self.name().filter(|_| self.name() == other.name()) {
             out = out.set_name(name);
         }
@@ -7066,7 +7092,7 @@ impl DatetimeIndex {
                 _ => i64::MIN,
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
@@ -7098,24 +7124,65 @@ impl DatetimeIndex {
 
     fn round_fixed_freq(&self, freq: &str, mode: TemporalRoundMode) -> Result<Self, IndexError> {
         let unit_nanos = parse_fixed_temporal_freq(freq, "DatetimeIndex rounding")?;
-        let nanos: Vec<i64> = self
-            .index
-            .labels()
-            .iter()
-            .map(|label| match label {
-                IndexLabel::Datetime64(n) if *n != i64::MIN => {
-                    round_nanos_to_unit(*n, unit_nanos, mode)
-                }
-                _ => i64::MIN,
+        self.map_local_nanos(
+            |n| round_nanos_to_unit(n, unit_nanos, mode),
+            TzAmbiguousPolicy::Raise,
+            TzNonexistentPolicy::Raise,
+        )
+    }
+
+    /// Apply `func` to each non-NAT label's wall-clock nanoseconds and read
+    /// the result back as wall-clock time, keeping the name and zone. For a
+    /// tz-aware index, day-based rounding therefore lands on local midnight,
+    /// the bin edge that daily resampling uses.
+    fn map_local_nanos(
+        &self,
+        func: impl Fn(i64) -> i64,
+        ambiguous: TzAmbiguousPolicy,
+        nonexistent: TzNonexistentPolicy,
+    ) -> Result<Self, IndexError> {
+        let walls: Vec<i64> = self
+            .asi8()
+            .into_iter()
+            .map(|n| match self.tz {
+                _ if n == i64::MIN => i64::MIN,
+                Some(tz) => func(tz.to_wall_nanos(n)),
+                None => func(n),
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let nanos = match self.tz {
+            Some(tz) => tz
+                .localize(&walls, ambiguous, nonexistent)
+                .map_err(|err| IndexError::InvalidArgument(err.to_string()))?,
+            None => walls,
+        };
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
         Ok(out)
     }
 
+    /// Map each label's wall-clock time; NAT propagates as `None`.
+    fn map_wall_clock<T, F>(&self, func: F) -> Vec<Option<T>>
+    where
+        F: Fn(chrono::DateTime<chrono::Utc>) -> T,
+    {
+        let Some(tz) = self.tz else {
+            return map_datetime_labels(self.index.labels(), func);
+        };
+        self.index
+            .labels()
+            .iter()
+            .map(|label| match label {
+                IndexLabel::Datetime64(nanos) => {
+                    datetime_from_nanos(tz.to_wall_nanos(*nanos)).map(&func)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Round timestamps down to a fixed pandas frequency.
     pub fn floor(&self, freq: &str) -> Result<Self, IndexError> {
         self.round_fixed_freq(freq, TemporalRoundMode::Floor)
@@ -7266,7 +7333,7 @@ impl DatetimeIndex {
                 _ => None,
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name().filter(|_| self.name() == other.name()) {
             out = out.set_name(name);
         }
@@ -7291,7 +7358,7 @@ impl DatetimeIndex {
                 nanos.push(*n);
             }
         }
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name().filter(|_| self.name() == other.name()) {
             out = out.set_name(name);
         }
@@ -7321,7 +7388,7 @@ impl DatetimeIndex {
                 _ => None,
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         // Per br-frankenpandas-6r1lq: difference is asymmetric — pandas
         // always preserves self.name (unlike union/intersection which use
         // shared_name).
@@ -7371,7 +7438,7 @@ impl DatetimeIndex {
                 nanos.push(*n);
             }
         }
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name().filter(|_| self.name() == other.name()) {
             out = out.set_name(name);
         }
@@ -7393,7 +7460,7 @@ impl DatetimeIndex {
             })
             .collect();
         nanos.sort_unstable();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
@@ -7425,7 +7492,7 @@ impl DatetimeIndex {
                 _ => None,
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
@@ -7458,7 +7525,7 @@ impl DatetimeIndex {
                 }
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
@@ -7490,7 +7557,7 @@ impl DatetimeIndex {
                 }
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
@@ -7540,7 +7607,7 @@ impl DatetimeIndex {
             })
             .collect();
         nanos.insert(loc, value);
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
@@ -7556,9 +7623,16 @@ impl DatetimeIndex {
             .labels()
             .iter()
             .map(|label| match label {
-                IndexLabel::Datetime64(nanos) => match datetime_from_nanos(*nanos) {
-                    Some(dt) => dt.to_rfc3339(),
-                    None => "NaT".to_owned(),
+                IndexLabel::Datetime64(nanos) => match (datetime_from_nanos(*nanos), self.tz) {
+                    (Some(dt), Some(tz)) => {
+                        let offset_secs = tz.utc_offset_nanos(*nanos) / Timedelta::NANOS_PER_SEC;
+                        i32::try_from(offset_secs)
+                            .ok()
+                            .and_then(chrono::FixedOffset::east_opt)
+                            .map_or_else(|| dt.to_rfc3339(), |o| dt.with_timezone(&o).to_rfc3339())
+                    }
+                    (Some(dt), None) => dt.to_rfc3339(),
+                    (None, _) => "NaT".to_owned(),
                 },
                 _ => "NaT".to_owned(),
             })
@@ -7578,7 +7652,7 @@ impl DatetimeIndex {
                 _ => value,
             })
             .collect();
-        let mut out = Self::new(nanos);
+        let mut out = Self::new(nanos).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             out = out.set_name(name);
         }
@@ -7600,19 +7674,19 @@ impl DatetimeIndex {
     /// Calendar date part of each label, matching `pd.DatetimeIndex.date`.
     #[must_use]
     pub fn date(&self) -> Vec<Option<chrono::NaiveDate>> {
-        map_datetime_labels(self.index.labels(), |dt| dt.date_naive())
+        self.map_wall_clock(|dt| dt.date_naive())
     }
 
     /// Within-day clock time of each label, matching
     /// `pd.DatetimeIndex.time`.
     #[must_use]
     pub fn time(&self) -> Vec<Option<chrono::NaiveTime>> {
-        map_datetime_labels(self.index.labels(), |dt| dt.time())
+        self.map_wall_clock(|dt| dt.time())
     }
 
     /// Time component preserving timezone semantics, matching
-    /// `pd.DatetimeIndex.timetz`. FrankenPandas currently stores
-    /// timezone-naive UTC nanoseconds, so this matches [`Self::time`].
+    /// `pd.DatetimeIndex.timetz`. This is the local wall-clock time, as
+    /// [`Self::time`]; the zone itself is [`Self::tz`].
     #[must_use]
     pub fn timetz(&self) -> Vec<Option<chrono::NaiveTime>> {
         self.time()
@@ -7639,35 +7713,71 @@ impl DatetimeIndex {
             .collect()
     }
 
-    /// Annotate a tz-naive index with `tz`, matching
-    /// `pd.DatetimeIndex.tz_localize(tz)`. FrankenPandas's storage is
-    /// already UTC-naive so localizing to `"UTC"` is a no-op clone;
-    /// every other timezone rejects until full tz metadata lands.
-    pub fn tz_localize(&self, tz: &str) -> Result<Self, IndexError> {
-        match tz {
-            "UTC" | "utc" => Ok(self.clone()),
-            other => Err(IndexError::InvalidArgument(format!(
-                "tz_localize: only 'UTC' is supported until timezone metadata lands; got {other:?}"
-            ))),
+    /// Read a tz-naive index as wall-clock times in `tz`, matching
+    /// `pd.DatetimeIndex.tz_localize(tz)`. `None` drops the zone of a
+    /// tz-aware index and keeps its local wall clock. Wall times in a DST
+    /// fold or gap raise; see [`Self::tz_localize_with`].
+    pub fn tz_localize(&self, tz: Option<&str>) -> Result<Self, IndexError> {
+        self.tz_localize_with(tz, TzAmbiguousPolicy::Raise, TzNonexistentPolicy::Raise)
+    }
+
+    /// [`Self::tz_localize`] with pandas' `ambiguous=` / `nonexistent=`
+    /// policies for DST folds and gaps.
+    pub fn tz_localize_with(
+        &self,
+        tz: Option<&str>,
+        ambiguous: TzAmbiguousPolicy,
+        nonexistent: TzNonexistentPolicy,
+    ) -> Result<Self, IndexError> {
+        let tz = tz.map(parse_time_zone).transpose()?;
+        let nanos = match (self.tz, tz) {
+            (None, None) => return Ok(self.clone()),
+            (Some(_), Some(_)) => {
+                return Err(IndexError::InvalidArgument(
+                    "tz_localize: Already tz-aware, use tz_convert to convert.".to_owned(),
+                ));
+            }
+            (None, Some(zone)) => zone
+                .localize(&self.asi8(), ambiguous, nonexistent)
+                .map_err(|err| IndexError::InvalidArgument(format!("tz_localize: {err}")))?,
+            (Some(zone), None) => self
+                .asi8()
+                .into_iter()
+                .map(|n| zone.to_wall_nanos(n))
+                .collect(),
+        };
+        let mut out = Self::new(nanos).with_time_zone(tz);
+        if let Some(name) = self.name() {
+            out = out.set_name(name);
         }
+        Ok(out)
     }
 
-    /// Convert a tz-aware index from its current zone to `tz`, matching
-    /// `pd.DatetimeIndex.tz_convert(tz)`. FrankenPandas indexes are
-    /// tz-naive (no source timezone) so this always rejects.
-    pub fn tz_convert(&self, _tz: &str) -> Result<Self, IndexError> {
-        Err(IndexError::InvalidArgument(
-            "tz_convert: cannot convert tz-naive timestamps; call tz_localize('UTC') first"
-                .to_owned(),
-        ))
+    /// Show a tz-aware index in another zone, matching
+    /// `pd.DatetimeIndex.tz_convert(tz)`. The instants are unchanged;
+    /// `None` converts to naive UTC.
+    pub fn tz_convert(&self, tz: Option<&str>) -> Result<Self, IndexError> {
+        if self.tz.is_none() {
+            return Err(IndexError::InvalidArgument(
+                "tz_convert: Cannot convert tz-naive timestamps, use tz_localize to localize"
+                    .to_owned(),
+            ));
+        }
+        let tz = tz.map(parse_time_zone).transpose()?;
+        Ok(self.clone().with_time_zone(tz))
     }
 
-    /// Timezone label, matching `pd.DatetimeIndex.tz`. FrankenPandas
-    /// stores naive UTC nanos so this always returns `None`; a
-    /// follow-up bead will introduce timezone metadata.
+    /// Timezone name, matching `pd.DatetimeIndex.tz`. `None` for a
+    /// tz-naive index.
     #[must_use]
     pub fn tz(&self) -> Option<String> {
-        None
+        self.tz.map(|tz| tz.name().to_owned())
+    }
+
+    /// The typed zone behind [`Self::tz`].
+    #[must_use]
+    pub fn time_zone(&self) -> Option<TimeZone> {
+        self.tz
     }
 
     /// Alias for [`tz`](Self::tz), matching `pd.DatetimeIndex.tzinfo`.
@@ -8008,7 +8118,7 @@ impl DatetimeIndex {
                 _ => None,
             })
             .collect();
-        let mut filtered = Self::new(surviving);
+        let mut filtered = Self::new(surviving).with_time_zone(self.tz);
         if let Some(name) = self.name() {
             filtered = filtered.set_name(name);
         }
@@ -8018,40 +8128,40 @@ impl DatetimeIndex {
     #[must_use]
     pub fn year(&self) -> Vec<Option<i32>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.year())
+        self.map_wall_clock(|dt| dt.year())
     }
 
     #[must_use]
     pub fn month(&self) -> Vec<Option<u32>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.month())
+        self.map_wall_clock(|dt| dt.month())
     }
 
     #[must_use]
     pub fn day(&self) -> Vec<Option<u32>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.day())
+        self.map_wall_clock(|dt| dt.day())
     }
 
     /// Hour of day per label (0..=23), matching `pd.DatetimeIndex.hour`.
     #[must_use]
     pub fn hour(&self) -> Vec<Option<u32>> {
         use chrono::Timelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.hour())
+        self.map_wall_clock(|dt| dt.hour())
     }
 
     /// Minute of hour per label (0..=59), matching `pd.DatetimeIndex.minute`.
     #[must_use]
     pub fn minute(&self) -> Vec<Option<u32>> {
         use chrono::Timelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.minute())
+        self.map_wall_clock(|dt| dt.minute())
     }
 
     /// Second of minute per label (0..=59), matching `pd.DatetimeIndex.second`.
     #[must_use]
     pub fn second(&self) -> Vec<Option<u32>> {
         use chrono::Timelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.second())
+        self.map_wall_clock(|dt| dt.second())
     }
 
     /// Microsecond component (0..=999_999), matching `pd.DatetimeIndex.microsecond`.
@@ -8059,7 +8169,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn microsecond(&self) -> Vec<Option<u32>> {
         use chrono::Timelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.nanosecond() / 1_000)
+        self.map_wall_clock(|dt| dt.nanosecond() / 1_000)
     }
 
     /// Nanosecond component (0..=999), matching `pd.DatetimeIndex.nanosecond`.
@@ -8067,7 +8177,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn nanosecond(&self) -> Vec<Option<u32>> {
         use chrono::Timelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.nanosecond() % 1_000)
+        self.map_wall_clock(|dt| dt.nanosecond() % 1_000)
     }
 
     /// Integer positions whose clock time equals `time`, matching
@@ -8118,7 +8228,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn week(&self) -> Vec<Option<u32>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.iso_week().week())
+        self.map_wall_clock(|dt| dt.iso_week().week())
     }
 
     /// ISO calendar `(year, week, weekday)` triples, matching
@@ -8127,7 +8237,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn isocalendar(&self) -> Vec<Option<(i32, u32, u32)>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
+        self.map_wall_clock(|dt| {
             let iso = dt.iso_week();
             (iso.year(), iso.week(), dt.weekday().number_from_monday())
         })
@@ -8143,7 +8253,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn dayofyear(&self) -> Vec<Option<u32>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.ordinal())
+        self.map_wall_clock(|dt| dt.ordinal())
     }
 
     /// Alias for [`dayofyear`](Self::dayofyear), matching `pd.DatetimeIndex.day_of_year`.
@@ -8157,9 +8267,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn dayofweek(&self) -> Vec<Option<u32>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
-            dt.weekday().num_days_from_monday()
-        })
+        self.map_wall_clock(|dt| dt.weekday().num_days_from_monday())
     }
 
     /// Alias for [`dayofweek`](Self::dayofweek), matching `pd.DatetimeIndex.day_of_week`.
@@ -8178,7 +8286,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn quarter(&self) -> Vec<Option<u32>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| (dt.month() - 1) / 3 + 1)
+        self.map_wall_clock(|dt| (dt.month() - 1) / 3 + 1)
     }
 
     /// Whether the year is a leap year, matching
@@ -8186,7 +8294,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn is_leap_year(&self) -> Vec<Option<bool>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
+        self.map_wall_clock(|dt| {
             chrono::NaiveDate::from_ymd_opt(dt.year(), 1, 1).is_some_and(|d| d.leap_year())
         })
     }
@@ -8196,9 +8304,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn days_in_month(&self) -> Vec<Option<u32>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
-            days_in_calendar_month(dt.year(), dt.month())
-        })
+        self.map_wall_clock(|dt| days_in_calendar_month(dt.year(), dt.month()))
     }
 
     /// Alias for [`days_in_month`](Self::days_in_month), matching `pd.DatetimeIndex.daysinmonth`.
@@ -8212,7 +8318,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn is_month_start(&self) -> Vec<Option<bool>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.day() == 1)
+        self.map_wall_clock(|dt| dt.day() == 1)
     }
 
     /// Whether the day is the last of the month, matching
@@ -8220,9 +8326,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn is_month_end(&self) -> Vec<Option<bool>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
-            dt.day() == days_in_calendar_month(dt.year(), dt.month())
-        })
+        self.map_wall_clock(|dt| dt.day() == days_in_calendar_month(dt.year(), dt.month()))
     }
 
     /// Whether the timestamp is the first day of a quarter, matching
@@ -8230,9 +8334,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn is_quarter_start(&self) -> Vec<Option<bool>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
-            matches!(dt.month(), 1 | 4 | 7 | 10) && dt.day() == 1
-        })
+        self.map_wall_clock(|dt| matches!(dt.month(), 1 | 4 | 7 | 10) && dt.day() == 1)
     }
 
     /// Whether the timestamp is the last day of a quarter, matching
@@ -8240,7 +8342,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn is_quarter_end(&self) -> Vec<Option<bool>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
+        self.map_wall_clock(|dt| {
             matches!(dt.month(), 3 | 6 | 9 | 12)
                 && dt.day() == days_in_calendar_month(dt.year(), dt.month())
         })
@@ -8251,7 +8353,7 @@ impl DatetimeIndex {
     #[must_use]
     pub fn is_year_start(&self) -> Vec<Option<bool>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.month() == 1 && dt.day() == 1)
+        self.map_wall_clock(|dt| dt.month() == 1 && dt.day() == 1)
     }
 
     /// Whether the timestamp is December 31, matching
@@ -8259,59 +8361,47 @@ impl DatetimeIndex {
     #[must_use]
     pub fn is_year_end(&self) -> Vec<Option<bool>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| dt.month() == 12 && dt.day() == 31)
+        self.map_wall_clock(|dt| dt.month() == 12 && dt.day() == 31)
     }
 
     /// Full English month name, matching `pd.DatetimeIndex.month_name()`.
     #[must_use]
     pub fn month_name(&self) -> Vec<Option<String>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
-            month_name_english(dt.month()).to_owned()
-        })
+        self.map_wall_clock(|dt| month_name_english(dt.month()).to_owned())
     }
 
     /// Full English weekday name, matching `pd.DatetimeIndex.day_name()`.
     #[must_use]
     pub fn day_name(&self) -> Vec<Option<String>> {
         use chrono::Datelike;
-        map_datetime_labels(self.index.labels(), |dt| {
-            weekday_name_english(dt.weekday()).to_owned()
-        })
+        self.map_wall_clock(|dt| weekday_name_english(dt.weekday()).to_owned())
     }
 
-    /// Truncate every timestamp to midnight UTC, matching
-    /// `pd.DatetimeIndex.normalize()`. NAT labels propagate.
+    /// Truncate every timestamp to midnight (local midnight when
+    /// tz-aware), matching `pd.DatetimeIndex.normalize()`. NAT labels
+    /// propagate. A midnight skipped by DST moves to the end of the gap.
     #[must_use]
     pub fn normalize(&self) -> Self {
-        let nanos: Vec<i64> = self
-            .index
-            .labels()
-            .iter()
-            .map(|label| match label {
-                IndexLabel::Datetime64(nanos) if *nanos != i64::MIN => {
-                    let secs_per_day: i64 = 86_400;
-                    let nanos_per_day: i64 = secs_per_day * 1_000_000_000;
-                    nanos.div_euclid(nanos_per_day) * nanos_per_day
-                }
-                _ => i64::MIN,
-            })
-            .collect();
-        let mut normalized = Self::new(nanos);
-        if let Some(name) = self.name() {
-            normalized = normalized.set_name(name);
-        }
-        normalized
+        let nanos_per_day = Timedelta::NANOS_PER_DAY;
+        // Earliest/ShiftForward resolve every fold and gap, so this cannot fail.
+        self.map_local_nanos(
+            |nanos| nanos.div_euclid(nanos_per_day) * nanos_per_day,
+            TzAmbiguousPolicy::Earliest,
+            TzNonexistentPolicy::ShiftForward,
+        )
+        .unwrap_or_else(|_| self.clone())
     }
 
-    /// Whether every label is at midnight UTC (NAT counts as normalized),
-    /// matching `pd.DatetimeIndex.is_normalized`.
+    /// Whether every label is at (local) midnight, NAT counting as
+    /// normalized, matching `pd.DatetimeIndex.is_normalized`.
     #[must_use]
     pub fn is_normalized(&self) -> bool {
         let nanos_per_day: i64 = 86_400 * 1_000_000_000;
         self.index.labels().iter().all(|label| match label {
             IndexLabel::Datetime64(nanos) => {
-                *nanos == i64::MIN || nanos.rem_euclid(nanos_per_day) == 0
+                let wall = self.tz.map_or(*nanos, |tz| tz.to_wall_nanos(*nanos));
+                *nanos == i64::MIN || wall.rem_euclid(nanos_per_day) == 0
             }
             _ => true,
         })
@@ -28181,28 +28271,105 @@ mod tests {
     #[test]
     fn datetime_index_tz_localize_tz_convert_match_pandas_qm31w() {
         const NS: i64 = 1_000_000_000;
-        let dt = super::DatetimeIndex::new(vec![1_704_067_200_i64 * NS]).set_name("ts");
-
-        // UTC is a no-op clone.
-        let utc = dt.tz_localize("UTC").expect("UTC localize");
-        assert!(utc.equals(&dt));
+        const HOUR: i64 = 3_600 * NS;
+        // 2024-01-01T00:00:00 wall clock.
+        let midnight = 1_704_067_200_i64 * NS;
+        let dt = super::DatetimeIndex::new(vec![midnight, i64::MIN]).set_name("ts");
+
+        // UTC keeps the instants and records the zone.
+        let utc = dt.tz_localize(Some("UTC")).expect("UTC localize");
+        assert_eq!(utc.asi8(), dt.asi8());
+        assert_eq!(utc.tz().as_deref(), Some("UTC"));
         assert_eq!(utc.name(), Some("ts"));
 
-        // Other timezones reject.
-        let err = dt.tz_localize("US/Eastern").unwrap_err();
+        // Eastern midnight is 05:00 UTC in winter; fields read the wall clock.
+        let eastern = dt
+            .tz_localize(Some("US/Eastern"))
+            .expect("Eastern localize");
+        assert_eq!(eastern.asi8(), vec![midnight + 5 * HOUR, i64::MIN]);
+        assert_eq!(eastern.tz().as_deref(), Some("US/Eastern"));
+        assert_eq!(eastern.hour(), vec![Some(0), None]);
+        assert_eq!(eastern.format()[0], "2024-01-01T00:00:00-05:00");
+        assert!(eastern.tz_localize(Some("UTC")).is_err());
+        assert_eq!(eastern.tz_localize(None).unwrap(), dt);
+        assert!(dt.tz_localize(Some("Mars/Olympus")).is_err());
+
+        // tz_convert keeps the instants and needs a tz-aware index.
+        let paris = eastern.tz_convert(Some("Europe/Paris")).expect("convert");
+        assert_eq!(paris.asi8(), eastern.asi8());
+        assert_eq!(paris.hour(), vec![Some(6), None]);
+        assert_eq!(paris.tz_convert(None).unwrap().asi8(), eastern.asi8());
+        assert_eq!(paris.tz_convert(None).unwrap().tz(), None);
+        let conv_err = dt.tz_convert(Some("UTC")).unwrap_err();
         assert!(matches!(
-            err,
+            conv_err,
             super::IndexError::InvalidArgument(ref message)
-                if message.contains("tz_localize") && message.contains("UTC")
+                if message.contains("tz_convert")
         ));
+    }
 
-        // tz_convert always rejects.
-        let conv_err = dt.tz_convert("UTC").unwrap_err();
+    #[test]
+    fn tz_aware_datetime_index_follows_dst_and_floors_to_local_midnight() {
+        use fp_types::{TzAmbiguousPolicy, TzNonexistentPolicy};
+
+        const NS: i64 = 1_000_000_000;
+        const HOUR: i64 = 3_600 * NS;
+        // 2021-11-07T00:00:00 wall clock; US clocks fell back at 02:00 EDT.
+        let day = 1_636_243_200_i64 * NS;
+        let walls = super::DatetimeIndex::new(vec![
+            day + HOUR / 2,
+            day + 3 * HOUR / 2,
+            day + 3 * HOUR / 2,
+            day + 3 * HOUR,
+        ]);
+        let err = walls.tz_localize(Some("America/New_York")).unwrap_err();
         assert!(matches!(
-            conv_err,
-            super::IndexError::InvalidArgument(ref message)
-                if message.contains("tz_convert")
+            err,
+            super::IndexError::InvalidArgument(ref message) if message.contains("ambiguous")
         ));
+        let ny = walls
+            .tz_localize_with(
+                Some("America/New_York"),
+                TzAmbiguousPolicy::Infer,
+                TzNonexistentPolicy::Raise,
+            )
+            .unwrap();
+        // EDT is UTC-4, EST is UTC-5: the repeated 01:30 is one hour apart.
+        assert_eq!(
+            ny.asi8(),
+            vec![
+                day + 9 * HOUR / 2,
+                day + 11 * HOUR / 2,
+                day + 13 * HOUR / 2,
+                day + 8 * HOUR,
+            ]
+        );
+        assert_eq!(ny.hour(), vec![Some(0), Some(1), Some(1), Some(3)]);
+        assert_eq!(ny.day(), vec![Some(7); 4]);
+
+        // Daily bins start at local midnight, which is 04:00 UTC that day.
+        let floored = ny.floor("D").unwrap();
+        assert_eq!(floored.asi8(), vec![day + 4 * HOUR; 4]);
+        assert_eq!(floored.tz(), ny.tz());
+        assert_eq!(ny.normalize(), floored);
+        assert!(floored.is_normalized());
+        assert!(!ny.is_normalized());
+        // Ceiling 00:30 lands on the repeated 01:00, which raises like pandas.
+        assert!(ny.ceil("h").is_err());
+
+        // 02:30 on 2021-03-14 never happened in New York.
+        let spring = 1_615_680_000_i64 * NS + 5 * HOUR / 2;
+        let gap = super::DatetimeIndex::new(vec![spring]);
+        assert!(gap.tz_localize(Some("America/New_York")).is_err());
+        let shifted = gap
+            .tz_localize_with(
+                Some("America/New_York"),
+                TzAmbiguousPolicy::Raise,
+                TzNonexistentPolicy::ShiftForward,
+            )
+            .unwrap();
+        assert_eq!(shifted.hour(), vec![Some(3)]);
+        assert_eq!(shifted.minute(), vec![Some(0)]);
     }
 
     #[test]
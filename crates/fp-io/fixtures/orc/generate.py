@@ -0,0 +1,272 @@
+"""Regenerate the ORC reader fixtures in this directory.
+
+The files follow the layout the Apache ORC C++ writer (and therefore
+pyarrow) emits: ROW_INDEX streams ahead of the data streams, per-column
+statistics in the footer, a stripe-statistics metadata section, a
+writer timezone in the stripe footer, and every stream, stripe footer,
+metadata section and footer compressed on its own. Stream payloads use
+the worked RLE v2 examples from the ORC specification (PATCHED_BASE,
+DIRECT, DELTA) rather than fp-io's own encoder, and compression uses
+CPython's zlib and the snappy encoder below, so the reader is checked
+against bytes fp-io never produced.
+
+Usage: python3 generate.py
+"""
+
+import struct
+import zlib
+from pathlib import Path
+
+HERE = Path(__file__).resolve().parent
+
+
+def varint(value):
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def zigzag(value):
+    return (value << 1) ^ (value >> 63)
+
+
+def field_varint(number, value):
+    return varint(number << 3) + varint(value)
+
+
+def field_bytes(number, payload):
+    return varint((number << 3) | 2) + varint(len(payload)) + payload
+
+
+def field_double(number, value):
+    return varint((number << 3) | 1) + struct.pack("<d", value)
+
+
+def packed(number, values):
+    return field_bytes(number, b"".join(varint(v) for v in values))
+
+
+def snappy_compress(data):
+    """Greedy snappy block encoder (literal + 2-byte-offset copies)."""
+    out = bytearray(varint(len(data)))
+    table = {}
+    pos = literal_start = 0
+
+    def emit_literal(end):
+        chunk = data[literal_start:end]
+        while chunk:
+            piece, chunk = chunk[:60], chunk[60:]
+            out.append((len(piece) - 1) << 2)
+            out.extend(piece)
+
+    while pos + 4 <= len(data):
+        key = data[pos : pos + 4]
+        candidate = table.get(key)
+        table[key] = pos
+        if candidate is None or pos - candidate > 0xFFFF:
+            pos += 1
+            continue
+        emit_literal(pos)
+        length = 4
+        while pos + length < len(data) and data[candidate + length] == data[pos + length]:
+            length += 1
+        offset = pos - candidate
+        remaining = length
+        while remaining:
+            take = min(remaining, 64)
+            out.append(((take - 1) << 2) | 2)
+            out.extend(struct.pack("<H", offset))
+            remaining -= take
+        pos += length
+        literal_start = pos
+    emit_literal(len(data))
+    return bytes(out)
+
+
+def deflate(data):
+    compressor = zlib.compressobj(9, zlib.DEFLATED, -15)
+    return compressor.compress(data) + compressor.flush()
+
+
+def frame(data, codec):
+    """ORC compression framing: 3-byte little-endian header per chunk."""
+    if not data:
+        return b""
+    compressed = codec(data)
+    if len(compressed) >= len(data):
+        header = len(data) * 2 + 1
+        compressed = data
+    else:
+        header = len(compressed) * 2
+    return header.to_bytes(3, "little") + compressed
+
+
+# ── column payloads ──────────────────────────────────────────────────
+
+ROWS = 20
+# ORC spec PATCHED_BASE example; signed LONG columns read it unchanged.
+N_DATA = bytes.fromhex(
+    "8e132b2107d01e00147028323c46505a646e78828c96a0aab4befce8"
+)
+N_VALUES = [2030, 2000, 2020, 1000000] + [2040 + 10 * i for i in range(16)]
+
+DICTIONARY = [b"x", b"yy", b"zzz"]
+S_INDICES = [i % 3 for i in range(ROWS)]
+
+
+def direct_v2_unsigned(values, width):
+    length = len(values) - 1
+    header = bytes([0x40 | ((width - 1) << 1) | (length >> 8), length & 0xFF])
+    bits = "".join(format(v, f"0{width}b") for v in values)
+    bits += "0" * (-len(bits) % 8)
+    return header + bytes(int(bits[i : i + 8], 2) for i in range(0, len(bits), 8))
+
+
+S_DATA = direct_v2_unsigned(S_INDICES, 2)
+# Fixed-delta DELTA run: base 1, delta +1, three values -> [1, 2, 3].
+S_LENGTH = bytes([0xC0, 0x02, 0x01, zigzag(1)])
+S_DICTIONARY = b"".join(DICTIONARY)
+
+F_NULLS = {5, 17}
+F_VALUES = [None if i in F_NULLS else i * 0.5 - 3.0 for i in range(ROWS)]
+present_bits = "".join("0" if v is None else "1" for v in F_VALUES)
+present_bits += "0" * (-len(present_bits) % 8)
+present_bytes = bytes(int(present_bits[i : i + 8], 2) for i in range(0, len(present_bits), 8))
+# Byte RLE literal run of the packed validity bits.
+F_PRESENT = bytes([256 - len(present_bytes)]) + present_bytes
+F_DATA = b"".join(struct.pack("<d", v) for v in F_VALUES if v is not None)
+
+
+def statistics(count, extra=b"", has_null=False):
+    return field_varint(1, count) + extra + field_varint(10, int(has_null))
+
+
+def int_statistics(values):
+    body = (
+        field_varint(1, zigzag(min(values)))
+        + field_varint(2, zigzag(max(values)))
+        + field_varint(3, zigzag(sum(values)))
+    )
+    return field_bytes(2, body)
+
+
+def double_statistics(values):
+    body = field_double(1, min(values)) + field_double(2, max(values)) + field_double(3, sum(values))
+    return field_bytes(3, body)
+
+
+def string_statistics(values):
+    body = (
+        field_bytes(1, min(values))
+        + field_bytes(2, max(values))
+        + field_varint(3, zigzag(sum(len(v) for v in values)))
+    )
+    return field_bytes(4, body)
+
+
+F_PRESENT_VALUES = [v for v in F_VALUES if v is not None]
+S_VALUES = [DICTIONARY[i] for i in S_INDICES]
+COLUMN_STATS = [
+    statistics(ROWS),
+    statistics(ROWS, int_statistics(N_VALUES)),
+    statistics(ROWS, string_statistics(S_VALUES)),
+    statistics(len(F_PRESENT_VALUES), double_statistics(F_PRESENT_VALUES), has_null=True),
+]
+
+
+def row_index(column):
+    positions = [0, 0, 0, 0] if column else []
+    entry = (packed(1, positions) if positions else b"") + field_bytes(2, COLUMN_STATS[column])
+    return field_bytes(1, entry)
+
+
+KIND_PRESENT, KIND_DATA, KIND_LENGTH, KIND_DICTIONARY_DATA, KIND_ROW_INDEX = 0, 1, 2, 3, 6
+
+
+def build(compression_kind, codec):
+    index_streams = [(KIND_ROW_INDEX, column, row_index(column)) for column in range(4)]
+    data_streams = [
+        (KIND_DATA, 1, N_DATA),
+        (KIND_DATA, 2, S_DATA),
+        (KIND_LENGTH, 2, S_LENGTH),
+        (KIND_DICTIONARY_DATA, 2, S_DICTIONARY),
+        (KIND_PRESENT, 3, F_PRESENT),
+        (KIND_DATA, 3, F_DATA),
+    ]
+    encoded = [(kind, column, frame(payload, codec)) for kind, column, payload in index_streams + data_streams]
+    index_length = sum(len(body) for _, _, body in encoded[: len(index_streams)])
+    data_length = sum(len(body) for _, _, body in encoded[len(index_streams) :])
+
+    stripe_footer = b"".join(
+        field_bytes(1, field_varint(1, kind) + field_varint(2, column) + field_varint(3, len(body)))
+        for kind, column, body in encoded
+    )
+    for kind, dictionary_size in [(0, 0), (2, 0), (3, len(DICTIONARY)), (0, 0)]:
+        body = field_varint(1, kind)
+        if dictionary_size:
+            body += field_varint(2, dictionary_size)
+        stripe_footer += field_bytes(2, body)
+    stripe_footer += field_bytes(3, b"GMT")
+    stripe_footer = frame(stripe_footer, codec)
+
+    out = bytearray(b"ORC")
+    stripe_offset = len(out)
+    for _, _, body in encoded:
+        out += body
+    out += stripe_footer
+    content_length = len(out) - 3
+
+    stripe_stats = field_bytes(1, b"".join(field_bytes(1, stats) for stats in COLUMN_STATS))
+    metadata = frame(field_bytes(1, stripe_stats), codec)
+    out += metadata
+
+    stripe_info = (
+        field_varint(1, stripe_offset)
+        + field_varint(2, index_length)
+        + field_varint(3, data_length)
+        + field_varint(4, len(stripe_footer))
+        + field_varint(5, ROWS)
+    )
+    types = [
+        field_varint(1, 12) + packed(2, [1, 2, 3]) + field_bytes(3, b"n") + field_bytes(3, b"s") + field_bytes(3, b"f"),
+        field_varint(1, 4),
+        field_varint(1, 7),
+        field_varint(1, 6),
+    ]
+    footer = (
+        field_varint(1, 3)
+        + field_varint(2, content_length)
+        + field_bytes(3, stripe_info)
+        + b"".join(field_bytes(4, t) for t in types)
+        + field_varint(6, ROWS)
+        + b"".join(field_bytes(7, stats) for stats in COLUMN_STATS)
+        + field_varint(8, 10000)
+        + field_varint(9, 1)
+        + field_bytes(12, b"1.9.2")
+    )
+    footer = frame(footer, codec)
+    out += footer
+
+    postscript = (
+        field_varint(1, len(footer))
+        + field_varint(2, compression_kind)
+        + field_varint(3, 262144)
+        + packed(4, [0, 12])
+        + field_varint(5, len(metadata))
+        + field_varint(6, 9)
+        + field_bytes(8000, b"ORC")
+    )
+    out += postscript
+    out.append(len(postscript))
+    return bytes(out)
+
+
+if __name__ == "__main__":
+    (HERE / "cpp_layout_zlib.orc").write_bytes(build(1, deflate))
+    (HERE / "cpp_layout_snappy.orc").write_bytes(build(2, snappy_compress))
@@ -348,6 +348,28 @@ fn reindex_outer_join_column(
     Ok(Column::new(fp_types::DType::Float64, values)?)
 }
 
+/// Keep a nullable extension key dtype (`Int64`/`boolean`) on a coalesced
+/// outer-merge key. The coalescing gathers rebuild plain Int64/Bool columns;
+/// when either side carries the masked dtype over the same base type, the
+/// merged key stays masked so right-only or unmatched rows surface `pd.NA`.
+fn retag_nullable_coalesced_key(
+    column: Column,
+    left_key_col: &Column,
+    right_key_col: &Column,
+) -> Column {
+    let (left, right) = (left_key_col.dtype(), right_key_col.dtype());
+    let nullable = if left.is_nullable() { left } else { right };
+    let base = nullable.to_non_nullable();
+    if nullable.is_nullable()
+        && left.to_non_nullable() == base
+        && right.to_non_nullable() == base
+        && (column.dtype() == base || column.dtype() == DType::Null)
+    {
+        return column.with_dtype(nullable);
+    }
+    column
+}
+
 fn coalesce_utf8_contiguous_key_column(
     left_key_col: &Column,
     right_key_col: &Column,
@@ -1014,6 +1036,43 @@ fn collect_join_key_columns<'a>(
     Ok(key_columns)
 }
 
+/// Tz-aware keys hold UTC instants while naive keys hold wall-clock values, so
+/// pairing one with the other would silently match unrelated rows. Two aware
+/// keys compare as instants even when their zones differ.
+fn validate_join_key_time_zones(
+    left_key_columns: &[&Column],
+    right_key_columns: &[&Column],
+    left_on: &[&str],
+    right_on: &[&str],
+) -> Result<(), JoinError> {
+    for (index, (left_key, right_key)) in left_key_columns.iter().zip(right_key_columns).enumerate()
+    {
+        let (left_dtype, right_dtype) = (left_key.dtype(), right_key.dtype());
+        if left_dtype.is_datetime()
+            && right_dtype.is_datetime()
+            && left_dtype.is_datetime_tz() != right_dtype.is_datetime_tz()
+        {
+            let describe = |dtype: DType| {
+                dtype.tz().map_or_else(
+                    || dtype.name().to_owned(),
+                    |tz| format!("datetime64[ns, {tz}]"),
+                )
+            };
+            return Err(JoinError::Frame(FrameError::CompatibilityRejected(
+                format!(
+                    "You are trying to merge on {} and {} columns for key '{}'/'{}'. \
+                 Use tz_localize or tz_convert so both keys are tz-aware",
+                    describe(left_dtype),
+                    describe(right_dtype),
+                    left_on[index],
+                    right_on[index]
+                ),
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn collect_composite_keys(key_columns: &[&Column]) -> Vec<CompositeJoinKey> {
     let row_count = key_columns.first().map_or(0, |column| column.len());
     let mut out = Vec::with_capacity(row_count);
@@ -7009,7 +7068,11 @@ fn build_single_key_ordered_unique_outer_merge_output(
                         }
                     }
                     if all_positions_valid {
-                        return Ok(Column::from_i64_values(data));
+                        return Ok(retag_nullable_coalesced_key(
+                            Column::from_i64_values(data),
+                            left_key_col,
+                            right_key_col,
+                        ));
                     }
                 }
 
@@ -7022,7 +7085,11 @@ fn build_single_key_ordered_unique_outer_merge_output(
                         (None, None) => fp_types::Scalar::Null(fp_types::NullKind::Null),
                     })
                     .collect::<Vec<_>>();
-                Ok(Column::from_values(values)?)
+                Ok(retag_nullable_coalesced_key(
+                    Column::from_values(values)?,
+                    left_key_col,
+                    right_key_col,
+                ))
             }
             ColBuild::Reindex {
                 col,
@@ -8912,6 +8979,7 @@ pub fn merge_dataframes_on_with_options(
 
     let left_key_columns = collect_join_key_columns(left, left_on, "left")?;
     let right_key_columns = collect_join_key_columns(right, right_on, "right")?;
+    validate_join_key_time_zones(&left_key_columns, &right_key_columns, left_on, right_on)?;
     let validate_allows_fast_positions = validate_mode_allows_fast_positions(validate_mode);
 
     if matches!(join_type, JoinType::Inner)
@@ -9666,7 +9734,9 @@ pub fn merge_dataframes_on_with_options(
                                 (None, None) => fp_types::Scalar::Null(fp_types::NullKind::Null),
                             })
                             .collect::<Vec<_>>();
-                        Column::from_values(values)
+                        Column::from_values(values).map(|column| {
+                            retag_nullable_coalesced_key(column, left_key_col, right_key_col)
+                        })
                     })?
                 };
                 insert_merged_output_column(
@@ -10684,6 +10754,7 @@ enum ByKey<'a> {
     Null(u8),
     Bool(bool),
     Int(i64),
+    Decimal(i128, u8),
     Str(&'a str),
     Timedelta(i64),
     Datetime(i64),
@@ -10698,18 +10769,31 @@ impl<'a> ByKey<'a> {
             Scalar::Null(NullKind::NaT) => ByKey::Null(2),
             Scalar::Bool(b) => ByKey::Bool(*b),
             Scalar::Int64(v) => ByKey::Int(*v),
+            Scalar::UInt64(v) => ByKey::Int(i64::try_from(*v).ok()?),
+            // Normalized so `1.50` matches `1.5`, and integral values match
+            // the equal integer key.
+            Scalar::Decimal(v) => {
+                let v = v.normalize();
+                match i64::try_from(v.value) {
+                    Ok(int) if v.scale == 0 => ByKey::Int(int),
+                    _ => ByKey::Decimal(v.value, v.scale),
+                }
+            }
             Scalar::Utf8(s) => ByKey::Str(s.as_str()),
             Scalar::Timedelta64(v) => ByKey::Timedelta(*v),
             Scalar::Datetime64(v) => ByKey::Datetime(*v),
             Scalar::Period(v) => ByKey::Period(v.ordinal),
-            Scalar::Float64(_) | Scalar::Interval(_) => return None,
+            Scalar::Float64(_) | Scalar::Interval(_) | Scalar::List(_) | Scalar::Struct(_) => {
+                return None;
+            }
         })
     }
 }
 
 /// Factorize a single `by` column over both frames into a shared u32 id space.
 /// Returns `None` (caller falls back to the string path) if any value is a
-/// `Float64`/`Interval` whose Debug string would not agree with typed equality.
+/// `Float64`/`Interval` or nested value whose Debug string would not agree
+/// with typed equality.
 fn try_factorize_typed<'a>(
     left: &'a [Scalar],
     right: &'a [Scalar],
@@ -11837,6 +11921,84 @@ mod tests {
         assert_eq!(order, ["k", "zebra", "apple", "mango"]);
     }
 
+    #[test]
+    fn merge_keeps_nullable_int_and_boolean_dtypes_through_fills() {
+        let null = Scalar::Null(NullKind::Null);
+        let nullable_frame = |columns: Vec<(&str, DType, Vec<Scalar>)>| {
+            let rows = columns[0].2.len();
+            let order = columns
+                .iter()
+                .map(|(name, _, _)| (*name).to_owned())
+                .collect();
+            let columns = columns
+                .into_iter()
+                .map(|(name, dtype, values)| (name.to_owned(), Column::new(dtype, values).unwrap()))
+                .collect();
+            DataFrame::new_with_column_order(
+                Index::new_known_unique_int64_unit_range(0, rows),
+                columns,
+                order,
+            )
+            .unwrap()
+        };
+        let left = nullable_frame(vec![
+            (
+                "id",
+                DType::Int64Nullable,
+                vec![Scalar::Int64(1), Scalar::Int64(2), Scalar::Int64(3)],
+            ),
+            (
+                "val_a",
+                DType::Int64Nullable,
+                vec![Scalar::Int64(10), null.clone(), Scalar::Int64(30)],
+            ),
+        ]);
+        let right = nullable_frame(vec![
+            (
+                "id",
+                DType::Int64Nullable,
+                vec![Scalar::Int64(2), Scalar::Int64(3), Scalar::Int64(4)],
+            ),
+            (
+                "flag",
+                DType::BoolNullable,
+                vec![Scalar::Bool(true), Scalar::Bool(false), Scalar::Bool(true)],
+            ),
+        ]);
+
+        let outer = merge_dataframes(&left, &right, "id", JoinType::Outer).unwrap();
+        assert_eq!(outer.columns["id"].dtype(), DType::Int64Nullable);
+        assert_eq!(
+            outer.columns["id"].values(),
+            &[
+                Scalar::Int64(1),
+                Scalar::Int64(2),
+                Scalar::Int64(3),
+                Scalar::Int64(4)
+            ]
+        );
+        assert_eq!(outer.columns["val_a"].dtype(), DType::Int64Nullable);
+        assert_eq!(
+            outer.columns["val_a"].values(),
+            &[
+                Scalar::Int64(10),
+                null.clone(),
+                Scalar::Int64(30),
+                null.clone()
+            ]
+        );
+        assert_eq!(outer.columns["flag"].dtype(), DType::BoolNullable);
+        assert_eq!(outer.columns["flag"].values()[0], null);
+
+        let left_join = merge_dataframes(&left, &right, "id", JoinType::Left).unwrap();
+        assert_eq!(left_join.columns["flag"].dtype(), DType::BoolNullable);
+        assert_eq!(
+            left_join.columns["flag"].values(),
+            &[null, Scalar::Bool(true), Scalar::Bool(false)]
+        );
+        assert_eq!(left_join.columns["val_a"].dtype(), DType::Int64Nullable);
+    }
+
     #[test]
     fn join_row_count_lattice_n8npw() {
         // Invariant (br-frankenpandas-n8npw): inner<=left<=outer and inner<=right<=outer
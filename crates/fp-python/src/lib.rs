@@ -87,12 +87,32 @@ fn scalar_to_py(py: Python<'_>, scalar: &Scalar) -> PyResult<Py<PyAny>> {
         Scalar::Null(_) => Ok(py.None()),
         Scalar::Bool(b) => b.into_py_any(py),
         Scalar::Int64(i) => i.into_py_any(py),
+        Scalar::UInt64(u) => u.into_py_any(py),
+        Scalar::Decimal(d) => Ok(py
+            .import("decimal")?
+            .getattr("Decimal")?
+            .call1((d.to_string(),))?
+            .unbind()),
         Scalar::Float64(f) => f.into_py_any(py),
         Scalar::Utf8(s) => s.into_py_any(py),
         Scalar::Datetime64(ns) => ns.into_py_any(py),
         Scalar::Timedelta64(ns) => ns.into_py_any(py),
         Scalar::Period(p) => p.ordinal.into_py_any(py),
         Scalar::Interval(_) => Ok(py.None()),
+        Scalar::List(items) => {
+            let items = items
+                .iter()
+                .map(|item| scalar_to_py(py, item))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyList::new(py, items)?.into_any().unbind())
+        }
+        Scalar::Struct(fields) => {
+            let dict = PyDict::new(py);
+            for (name, value) in fields {
+                dict.set_item(name, scalar_to_py(py, value)?)?;
+            }
+            Ok(dict.into_any().unbind())
+        }
     }
 }
 
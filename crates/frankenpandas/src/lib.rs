@@ -16,7 +16,9 @@
 
 // ── Core types ──────────────────────────────────────────────────────────
 
-pub use fp_columnar::{ArithmeticOp, Column, ColumnError, ComparisonOp, ValidityMask};
+pub use fp_columnar::{
+    ArithmeticOp, Column, ColumnError, ComparisonOp, ListAccessor, StructAccessor, ValidityMask,
+};
 // ── Expression engine ───────────────────────────────────────────────────
 pub use fp_expr::{
     DataFrameExprExt, Delta, EvalContext, Expr, ExprError, MaterializedView, SeriesRef, eval_str,
@@ -120,7 +122,10 @@ pub use fp_index::{
 };
 // ── IO functions ────────────────────────────────────────────────────────
 pub use fp_io::{
+    // Compression codecs (pandas compression=)
+    Compression,
     // CSV
+    CsvChunkIterator,
     CsvOnBadLines,
     CsvReadOptions,
     CsvWriteOptions,
@@ -128,24 +133,52 @@ pub use fp_io::{
     // Extension trait
     DataFrameIoExt,
     // Excel
+    ExcelIfSheetExists,
     ExcelReadOptions,
     ExcelWriteOptions,
+    ExcelWriter,
     // HDF5 / HTML
+    HdfFormat,
+    HdfMode,
     HdfReadOptions,
+    HdfStore,
     HdfWriteOptions,
+    HtmlExtractLinks,
     HtmlReadOptions,
     // Error type
     IoError,
     // JSON
+    JsonNormalizeErrors,
+    JsonNormalizeOptions,
     JsonOrient,
     // Markdown / LaTeX
     LatexWriteOptions,
     MarkdownWriteOptions,
+    // ORC
+    OrcCompression,
+    OrcWriteOptions,
+    // Parquet
+    ParquetBatchIterator,
+    ParquetCompression,
+    ParquetDatasetReadOptions,
+    ParquetFilter,
+    ParquetFilterOp,
+    ParquetReadOptions,
+    ParquetStatisticsLevel,
+    ParquetWriteOptions,
     // Pickle
     PickleProtocol,
     PickleReadOptions,
     PickleWriteOptions,
+    // SAS
+    SasChunkIterator,
+    SasColumn,
+    SasFormat,
+    SasReadOptions,
     SeriesIoExt,
+    // SPSS
+    SpssReadOptions,
+    SpssVariable,
     // SQL
     SqlBackendCaps,
     SqlChunkIterator,
@@ -161,11 +194,21 @@ pub use fp_io::{
     SqlReadOptions,
     SqlReflectedTable,
     SqlTableSchema,
+    SqlTransaction,
     SqlUniqueConstraintSchema,
     SqlWriteOptions,
+    SqlWriteSummary,
     // Stata
+    StataDateFormat,
+    StataMetadata,
+    StataReadOptions,
+    StataVariable,
+    StataVersion,
     StataWriteOptions,
     inspect,
+    json_normalize,
+    json_normalize_jsonl_str,
+    json_normalize_str,
     list_sql_foreign_keys,
     list_sql_indexes,
     list_sql_schemas,
@@ -173,6 +216,8 @@ pub use fp_io::{
     list_sql_unique_constraints,
     list_sql_views,
     read_csv,
+    read_csv_chunks,
+    read_csv_chunks_path,
     read_csv_str,
     read_csv_with_index_cols,
     read_csv_with_index_cols_path,
@@ -191,28 +236,55 @@ pub use fp_io::{
     // Feather (Arrow IPC)
     read_feather,
     read_feather_bytes,
+    read_feather_series,
+    read_feather_series_bytes,
     read_hdf,
+    read_hdf_bytes,
     read_hdf_key,
     read_hdf_with_options,
     read_html,
     read_html_str,
     read_html_str_with_options,
+    read_html_tables,
+    read_html_tables_str,
     read_ipc_stream_bytes,
     read_json,
     read_json_str,
+    read_json_with_compression,
     // JSONL
     read_jsonl,
     read_jsonl_str,
+    read_jsonl_with_compression,
     // ORC
     read_orc,
     read_orc_bytes,
     // Parquet
     read_parquet,
+    read_parquet_batches,
+    read_parquet_batches_bytes,
     read_parquet_bytes,
+    read_parquet_bytes_with_options,
+    read_parquet_dataset,
+    read_parquet_dataset_series,
+    read_parquet_series,
+    read_parquet_series_bytes,
+    read_parquet_with_options,
     read_pickle,
     read_pickle_bytes,
     read_pickle_bytes_with_options,
     read_pickle_with_options,
+    read_sas,
+    read_sas_bytes,
+    read_sas_chunks,
+    read_sas_chunks_bytes,
+    read_sas_with_options,
+    read_spss,
+    read_spss_bytes,
+    read_spss_metadata,
+    read_spss_metadata_bytes,
+    read_spss_series,
+    read_spss_series_bytes,
+    read_spss_with_options,
     read_sql,
     read_sql_chunks,
     read_sql_chunks_with_index_col,
@@ -243,10 +315,18 @@ pub use fp_io::{
     read_sql_with_options,
     read_stata,
     read_stata_bytes,
+    read_stata_bytes_with_options,
+    read_stata_metadata,
+    read_stata_metadata_bytes,
+    read_stata_series,
+    read_stata_series_bytes,
+    read_stata_with_options,
     // fd90.264: Series-level Arrow interop (README line 1580 mentions
     // DataFrame ↔ Arrow RecordBatch; these are the Series counterparts).
     series_from_arrow_array,
+    series_from_arrow_field,
     series_to_arrow_array,
+    series_to_arrow_field,
     sql_backend_caps,
     sql_max_identifier_length,
     sql_max_insert_rows,
@@ -255,26 +335,33 @@ pub use fp_io::{
     sql_server_version,
     sql_supports_returning,
     sql_supports_schemas,
+    sql_supports_transactions,
+    sql_supports_upsert,
     sql_table_comment,
     sql_table_schema,
     truncate_sql_table,
     write_csv,
     write_csv_string,
     write_csv_string_with_options,
+    write_csv_with_options,
     write_excel,
     write_excel_bytes,
     write_excel_bytes_with_options,
     write_excel_with_options,
     write_feather,
     write_feather_bytes,
+    write_feather_series,
+    write_feather_series_bytes,
     write_hdf,
     write_hdf_key,
     write_hdf_with_options,
     write_ipc_stream_bytes,
     write_json,
     write_json_string,
+    write_json_with_compression,
     write_jsonl,
     write_jsonl_string,
+    write_jsonl_with_compression,
     write_latex,
     write_latex_string,
     write_latex_string_with_options,
@@ -285,17 +372,28 @@ pub use fp_io::{
     write_markdown_with_options,
     write_orc,
     write_orc_bytes,
+    write_orc_bytes_with_options,
+    write_orc_with_options,
     write_parquet,
     write_parquet_bytes,
+    write_parquet_bytes_with_options,
+    write_parquet_dataset,
+    write_parquet_dataset_with_options,
+    write_parquet_series,
+    write_parquet_series_bytes,
+    write_parquet_with_options,
     write_pickle,
     write_pickle_bytes,
     write_pickle_bytes_with_options,
     write_pickle_with_options,
     write_sql,
     write_sql_with_options,
+    write_sql_with_summary,
     write_stata,
     write_stata_bytes,
     write_stata_bytes_with_options,
+    write_stata_series,
+    write_stata_series_bytes,
     write_stata_with_options,
 };
 // ── Join/merge ──────────────────────────────────────────────────────────
@@ -332,8 +430,10 @@ pub use fp_runtime::{
     decision_to_card,
 };
 pub use fp_types::{
-    DType, NullKind, Scalar, SparseDType, TypeError, cast_scalar, cast_scalar_owned, common_dtype,
-    count_na, dropna, fill_na, infer_dtype, isna, isnull, notna, notnull,
+    DECIMAL128_MAX_PRECISION, DType, Decimal, DecimalRounding, ListType, NullKind, NumericDowncast,
+    Scalar, SparseDType, StructField, StructType, TimeZone, TypeError, cast_scalar,
+    cast_scalar_owned, common_dtype, count_na, downcast_numeric_dtype, dropna, fill_na,
+    infer_dtype, isna, isnull, notna, notnull,
 };
 // fd90.263: pandas-equivalent helper types for Datetime64/Timedelta64/Period/Interval
 // scalar variants. Users typically interact via Scalar::Timedelta64(nanos) etc., but
@@ -371,6 +471,10 @@ pub use fp_types::{
 #[cfg(feature = "sql-sqlite")]
 pub use rusqlite;
 
+// The Tokio-free PostgreSQL adapter ships in fp-io behind `sql-postgresql`.
+#[cfg(feature = "sql-postgresql")]
+pub use fp_io::{PostgresConnectOptions, PostgresConnection};
+
 // ── Prelude ─────────────────────────────────────────────────────────────
 
 /// Convenience prelude that imports the most commonly used types and traits.
@@ -409,7 +513,11 @@ pub mod prelude {
         // Runtime — Bayesian decision inspection (README lines 378-403).
         // fd90.221: expose the types reachable via EvidenceLedger.records().
         CompatibilityIssue,
+        // Codec selector for the file-based readers/writers (compression=).
+        Compression,
         ConcatJoin,
+        // Streaming CSV iterator returned by read_csv_chunks (below).
+        CsvChunkIterator,
         CsvOnBadLines,
         CsvReadOptions,
         CsvWriteOptions,
@@ -450,8 +558,10 @@ pub mod prelude {
         EvidenceLedger,
         EvidenceTerm,
         Ewm,
+        ExcelIfSheetExists,
         ExcelReadOptions,
         ExcelWriteOptions,
+        ExcelWriter,
         Expanding,
         ExprError,
         FrameError,
@@ -461,7 +571,10 @@ pub mod prelude {
         GroupByOptions,
         GroupByResample,
         GroupByRolling,
+        HdfFormat,
+        HdfMode,
         HdfReadOptions,
+        HdfStore,
         HdfWriteOptions,
         Index,
         IndexError,
@@ -479,6 +592,8 @@ pub mod prelude {
         JoinExecutionOptions,
         JoinType,
         JoinedSeries,
+        JsonNormalizeErrors,
+        JsonNormalizeOptions,
         JsonOrient,
         LatexWriteOptions,
         MarkdownWriteOptions,
@@ -489,6 +604,19 @@ pub mod prelude {
         MultiIndex,
         MultiIndexOrIndex,
         NullKind,
+        // Native ORC codec and stripe options.
+        OrcCompression,
+        OrcWriteOptions,
+        // Parquet projection / row-group / filter pushdown options and the
+        // streaming iterator returned by read_parquet_batches.
+        ParquetBatchIterator,
+        ParquetCompression,
+        ParquetDatasetReadOptions,
+        ParquetFilter,
+        ParquetFilterOp,
+        ParquetReadOptions,
+        ParquetStatisticsLevel,
+        ParquetWriteOptions,
         Period,
         PeriodFreq,
         PeriodIndex,
@@ -497,6 +625,9 @@ pub mod prelude {
         Rolling,
         RuntimeMode,
         RuntimePolicy,
+        // Native sas7bdat / XPORT reader options and chunk iterator.
+        SasChunkIterator,
+        SasReadOptions,
         Scalar,
         Series,
         SeriesGroupBy,
@@ -507,6 +638,8 @@ pub mod prelude {
         // and the Scalar::Sparse workflow. Without this users couldn't
         // name the dtype after calling sparse().to_dense() etc.
         SparseDType,
+        // Native SPSS .sav / .zsav reader options.
+        SpssReadOptions,
         // SQL contracts (covers the README Quick Start round-trip).
         // fd90.206: also expose the option/inspector/chunked-read surface
         // documented in the IO Format Support table at line 148.
@@ -530,8 +663,11 @@ pub mod prelude {
         SqlReadOptions,
         SqlReflectedTable,
         SqlTableSchema,
+        SqlTransaction,
         SqlUniqueConstraintSchema,
         SqlWriteOptions,
+        SqlWriteSummary,
+        StataReadOptions,
         StataWriteOptions,
         StringAccessor,
         Timedelta,
@@ -601,6 +737,9 @@ pub mod prelude {
         isnull,
         join_series,
         join_series_with_options,
+        json_normalize,
+        json_normalize_jsonl_str,
+        json_normalize_str,
         // fd90.11: module-level SQL helpers (fd90.21-32). Free-function
         // counterparts to SqlInspector methods — paired surface, same
         // semantics. Promote alongside SqlInspector / inspect for
@@ -649,6 +788,7 @@ pub mod prelude {
         qcut,
         // IO — readers (in-memory + path; covers all 8 documented formats)
         read_csv,
+        read_csv_chunks,
         read_csv_str,
         // fd90.16: index-cols readers pair with read_csv_with_options
         // for the index_col argument shape pandas exposes.
@@ -667,18 +807,37 @@ pub mod prelude {
         read_excel_with_index_cols,
         read_feather,
         read_feather_bytes,
+        read_feather_series,
+        read_feather_series_bytes,
         read_hdf,
+        read_hdf_bytes,
         read_hdf_key,
         read_hdf_with_options,
         read_ipc_stream_bytes,
         read_json,
         read_json_str,
+        read_json_with_compression,
         read_jsonl,
         read_jsonl_str,
+        read_jsonl_with_compression,
         read_orc,
         read_orc_bytes,
         read_parquet,
+        read_parquet_batches,
+        read_parquet_batches_bytes,
         read_parquet_bytes,
+        read_parquet_bytes_with_options,
+        read_parquet_dataset,
+        read_parquet_dataset_series,
+        read_parquet_series,
+        read_parquet_series_bytes,
+        read_parquet_with_options,
+        read_sas,
+        read_sas_bytes,
+        read_sas_chunks,
+        read_spss,
+        read_spss_bytes,
+        read_spss_series,
         read_sql,
         read_sql_chunks,
         // fd90.20: paired producer for SqlIndexedChunkIterator (above).
@@ -696,12 +855,16 @@ pub mod prelude {
         read_sql_with_options,
         read_stata,
         read_stata_bytes,
+        read_stata_series,
+        read_stata_with_options,
         // fd90.12: Series ↔ Arrow array interop. README line 1580
         // documents Arrow interop as a public surface; fd90.264 added
         // the Series-level pair. Promote to the prelude alongside the
         // rest of the IO surface.
         series_from_arrow_array,
+        series_from_arrow_field,
         series_to_arrow_array,
+        series_to_arrow_field,
         sql_backend_caps,
         sql_max_identifier_length,
         sql_max_insert_rows,
@@ -710,6 +873,8 @@ pub mod prelude {
         sql_server_version,
         sql_supports_returning,
         sql_supports_schemas,
+        sql_supports_transactions,
+        sql_supports_upsert,
         sql_table_comment,
         sql_table_schema,
         timedelta_range,
@@ -727,20 +892,25 @@ pub mod prelude {
         write_csv,
         write_csv_string,
         write_csv_string_with_options,
+        write_csv_with_options,
         write_excel,
         write_excel_bytes,
         write_excel_bytes_with_options,
         write_excel_with_options,
         write_feather,
         write_feather_bytes,
+        write_feather_series,
+        write_feather_series_bytes,
         write_hdf,
         write_hdf_key,
         write_hdf_with_options,
         write_ipc_stream_bytes,
         write_json,
         write_json_string,
+        write_json_with_compression,
         write_jsonl,
         write_jsonl_string,
+        write_jsonl_with_compression,
         write_latex,
         write_latex_string,
         write_latex_string_with_options,
@@ -751,12 +921,21 @@ pub mod prelude {
         write_markdown_with_options,
         write_orc,
         write_orc_bytes,
+        write_orc_bytes_with_options,
+        write_orc_with_options,
         write_parquet,
         write_parquet_bytes,
+        write_parquet_bytes_with_options,
+        write_parquet_dataset,
+        write_parquet_dataset_with_options,
+        write_parquet_series,
+        write_parquet_series_bytes,
+        write_parquet_with_options,
         write_sql,
         // fd90.209: write_sql_with_options pairs with SqlWriteOptions
         // (which is in the prelude as of fd90.206).
         write_sql_with_options,
+        write_sql_with_summary,
         write_stata,
         write_stata_bytes,
         write_stata_bytes_with_options,
@@ -897,8 +1076,7 @@ mod tests {
         fn _takes_sql<C: SqlConnection>(_: &C) {}
         // fd90.206: SqlReadOptions / SqlWriteOptions / SqlInspector + read_sql_chunks.
         let _: SqlReadOptions = SqlReadOptions::default();
-        // SqlWriteOptions has no Default impl — type-check via fn pointer.
-        let _is_write_opts: fn(SqlWriteOptions) -> _ = |x| x;
+        let _: SqlWriteOptions = SqlWriteOptions::default();
         // SqlInspector is a struct; type-check via fn-pointer signature.
         // The rusqlite-typed assertions only compile with the `sql-sqlite`
         // feature (which is what brings rusqlite into scope as an optional
@@ -910,6 +1088,7 @@ mod tests {
             let _ = read_sql_chunks::<rusqlite::Connection>;
             // fd90.209: write_sql_with_options pairs with SqlWriteOptions.
             let _ = write_sql_with_options::<rusqlite::Connection>;
+            let _ = write_sql_with_summary::<rusqlite::Connection>;
             // fd90.210: read_sql_with_options pairs with SqlReadOptions.
             let _ = read_sql_with_options::<rusqlite::Connection>;
             // fd90.244: extra SQL reader variants.
@@ -994,25 +1173,72 @@ mod tests {
         // the function item to a value; the type is inferred and we don't need to
         // annotate the exact signature (which varies per IO format).
         let _ = read_csv;
+        let _ = read_csv_chunks::<std::fs::File>;
+        let _ = Compression::Infer;
         let _ = read_excel;
         let _ = read_excel_bytes;
         let _ = read_feather;
         let _ = read_feather_bytes;
+        let _ = read_feather_series;
+        let _ = read_feather_series_bytes;
         let _ = read_ipc_stream_bytes;
         let _ = read_json;
         let _ = read_jsonl;
         let _ = read_parquet;
         let _ = read_parquet_bytes;
+        let _ = read_parquet_series;
+        let _ = read_parquet_series_bytes;
+        let _ = read_parquet_with_options;
+        let _ = read_parquet_bytes_with_options;
+        let _ = read_parquet_batches;
+        let _ = read_parquet_batches_bytes;
+        let _: ParquetReadOptions = ParquetReadOptions::default();
+        let _is_parquet_batches: fn(ParquetBatchIterator) -> _ = |x| x;
+        let _ = ParquetFilter::isin;
+        let _ = ParquetFilterOp::NotIn;
         let _ = write_csv;
+        let _ = write_csv_with_options;
         let _ = write_excel;
         let _ = write_excel_bytes;
         let _ = write_feather;
         let _ = write_feather_bytes;
+        let _ = write_feather_series;
+        let _ = write_feather_series_bytes;
         let _ = write_ipc_stream_bytes;
         let _ = write_json;
+        let _ = write_json_with_compression;
         let _ = write_jsonl;
+        let _ = write_jsonl_with_compression;
         let _ = write_parquet;
         let _ = write_parquet_bytes;
+        let _ = write_parquet_series;
+        let _ = write_parquet_series_bytes;
+        let _ = write_parquet_with_options;
+        let _ = write_parquet_bytes_with_options;
+        let _: ParquetWriteOptions = ParquetWriteOptions::default();
+        let _ = ParquetCompression::Zstd;
+        let _ = ParquetStatisticsLevel::Chunk;
+        let _ = read_parquet_dataset;
+        let _ = read_parquet_dataset_series;
+        let _ = write_parquet_dataset;
+        let _ = write_parquet_dataset_with_options;
+        let _: ParquetDatasetReadOptions = ParquetDatasetReadOptions::default();
+        let _ = write_orc_with_options;
+        let _ = write_orc_bytes_with_options;
+        let _: OrcWriteOptions = OrcWriteOptions::default();
+        let _ = OrcCompression::Snappy;
+        let _ = read_sas;
+        let _ = read_sas_bytes;
+        let _ = read_sas_chunks;
+        let _: SasReadOptions = SasReadOptions::default();
+        let _is_sas_chunks: for<'a> fn(SasChunkIterator<'a>) -> SasChunkIterator<'a> = |x| x;
+        let _ = read_spss;
+        let _ = read_spss_bytes;
+        let _ = read_spss_series;
+        let _: SpssReadOptions = SpssReadOptions::default();
+        let _ = read_stata_with_options;
+        let _ = read_stata_series;
+        let _: StataReadOptions = StataReadOptions::default();
         // write_sql is generic over C: SqlConnection — exercised in the
         // rusqlite_reexport_quickstart_compiles test; bare let-binding
         // can't infer C without a concrete type, so skip here.
@@ -1038,6 +1264,13 @@ mod tests {
         let _ = write_csv_string_with_options;
         let _ = write_excel_with_options;
         let _ = write_excel_bytes_with_options;
+        let _: ExcelWriter = ExcelWriter::new();
+        let _ = ExcelIfSheetExists::default();
+        let _: JsonNormalizeOptions = JsonNormalizeOptions::default();
+        let _ = JsonNormalizeErrors::default();
+        let _ = json_normalize;
+        let _ = json_normalize_str;
+        let _ = json_normalize_jsonl_str;
 
         // fd90.217: merge_asof options + JoinExecutionOptions in prelude.
         let _: MergeAsofOptions = MergeAsofOptions::default();
@@ -1121,6 +1354,8 @@ mod tests {
             let _ = sql_server_version::<rusqlite::Connection>;
             let _ = sql_supports_returning::<rusqlite::Connection>;
             let _ = sql_supports_schemas::<rusqlite::Connection>;
+            let _ = sql_supports_transactions::<rusqlite::Connection>;
+            let _ = sql_supports_upsert::<rusqlite::Connection>;
             let _ = sql_table_comment::<rusqlite::Connection>;
             let _ = sql_table_schema::<rusqlite::Connection>;
             let _ = truncate_sql_table::<rusqlite::Connection>;
@@ -1129,6 +1364,8 @@ mod tests {
         // fd90.12: Series ↔ Arrow interop (paired with the README's
         // documented Arrow zero-copy claim at line 1580).
         let _ = series_to_arrow_array;
+        let _ = series_to_arrow_field;
+        let _ = series_from_arrow_field;
         // series_from_arrow_array is generic over `impl Into<String>`;
         // tests/readme_quick_example.rs::readme_series_arrow_round_trip
         // exercises it with concrete args.
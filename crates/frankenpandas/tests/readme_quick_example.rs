@@ -91,6 +91,11 @@ fn readme_quick_start_round_trip_through_sqlite() -> Result<(), Box<dyn std::err
         dtype: None,
         method: SqlInsertMethod::Single,
         chunksize: None,
+        primary_key: None,
+        not_null: false,
+        create_index: false,
+        conflict_columns: Vec::new(),
+        update_columns: None,
     };
     write_sql_with_options(&by_ticker, &conn, "results_v2", &write_opts)?;
     let read_opts = SqlReadOptions::default();
@@ -109,6 +114,7 @@ fn readme_quick_start_round_trip_through_sqlite() -> Result<(), Box<dyn std::err
         header: true,
         include_index: false,
         index_label: None,
+        ..CsvWriteOptions::default()
     };
     let csv_via_trait = by_ticker.to_csv_string_with_options(&csv_opts)?;
     assert!(csv_via_trait.contains(';'));
@@ -440,6 +446,11 @@ fn readme_quick_start_round_trip_through_sqlite() -> Result<(), Box<dyn std::err
         dtype: None,
         method: SqlInsertMethod::Multi,
         chunksize: None,
+        primary_key: None,
+        not_null: false,
+        create_index: false,
+        conflict_columns: Vec::new(),
+        update_columns: None,
     };
     write_sql_with_options(&by_ticker, &conn, "multi_method", &multi_opts)?;
     let multi_back = read_sql_table(&conn, "multi_method")?;
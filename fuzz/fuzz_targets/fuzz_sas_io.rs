@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+const MAX_SAS_BYTES: usize = 512 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() > MAX_SAS_BYTES {
+        return;
+    }
+
+    let _ = fp_conformance::fuzz_sas_io_bytes(data);
+});